@@ -0,0 +1,16 @@
+// Generated from Slang reflection, do not edit.
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Packed {
+    pub rotation: [f32; 11],
+    pub _pad0: [u8; 4],
+    pub weights: [f32; 9],
+    pub after: f32,
+    pub _pad1: [u8; 8],
+}
+
+const _: () = assert!(std::mem::size_of::<Packed>() == 96);
+const _: () = assert!(std::mem::offset_of!(Packed, rotation) == 0);
+const _: () = assert!(std::mem::offset_of!(Packed, weights) == 48);
+const _: () = assert!(std::mem::offset_of!(Packed, after) == 84);
@@ -0,0 +1,32 @@
+// Generated from Slang reflection, do not edit.
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Light {
+    pub position: [f32; 3],
+    pub intensity: f32,
+    pub color: [f32; 4],
+}
+
+const _: () = assert!(std::mem::size_of::<Light>() == 32);
+const _: () = assert!(std::mem::offset_of!(Light, position) == 0);
+const _: () = assert!(std::mem::offset_of!(Light, intensity) == 12);
+const _: () = assert!(std::mem::offset_of!(Light, color) == 16);
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Params {
+    pub view: [[f32; 4]; 4],
+    pub lights: [Light; 2],
+    pub weights: [[f32; 4]; 3],
+    pub time: f32,
+    pub count: u32,
+    pub _pad0: [u8; 8],
+}
+
+const _: () = assert!(std::mem::size_of::<Params>() == 192);
+const _: () = assert!(std::mem::offset_of!(Params, view) == 0);
+const _: () = assert!(std::mem::offset_of!(Params, lights) == 64);
+const _: () = assert!(std::mem::offset_of!(Params, weights) == 128);
+const _: () = assert!(std::mem::offset_of!(Params, time) == 176);
+const _: () = assert!(std::mem::offset_of!(Params, count) == 180);
@@ -0,0 +1,136 @@
+//! `#[derive(ShaderLayout)]`, see `slang::ShaderLayout`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
+
+/// Describes the fields of a `#[repr(C)]` struct so it can be checked against a Slang type layout.
+///
+/// Fields whose name starts with `_` are treated as padding. Field attributes:
+///
+/// - `#[shader(rename = "name")]` matches a shader field with a different name.
+/// - `#[shader(row_major)]` or `#[shader(column_major)]` checks the matrix layout mode.
+/// - `#[shader(skip)]` leaves the field out.
+#[proc_macro_derive(ShaderLayout, attributes(shader))]
+pub fn derive_shader_layout(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+
+	expand(input)
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+	let Data::Struct(data) = &input.data else {
+		return Err(syn::Error::new_spanned(
+			&input.ident,
+			"ShaderLayout can only be derived for structs",
+		));
+	};
+
+	let Fields::Named(fields) = &data.fields else {
+		return Err(syn::Error::new_spanned(
+			&input.ident,
+			"ShaderLayout requires named fields",
+		));
+	};
+
+	if !has_repr_c(&input)? {
+		return Err(syn::Error::new_spanned(
+			&input.ident,
+			"ShaderLayout requires #[repr(C)], field offsets aren't stable otherwise",
+		));
+	}
+
+	let mut layouts = Vec::new();
+
+	for field in &fields.named {
+		let ident = field.ident.as_ref().unwrap();
+		let ty = &field.ty;
+
+		let mut name = ident.to_string().trim_start_matches("r#").to_string();
+		let mut skip = name.starts_with('_');
+		let mut matrix_layout_mode = quote!(::core::option::Option::None);
+
+		for attr in field
+			.attrs
+			.iter()
+			.filter(|attr| attr.path().is_ident("shader"))
+		{
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("rename") {
+					name = meta.value()?.parse::<LitStr>()?.value();
+				} else if meta.path.is_ident("skip") {
+					skip = true;
+				} else if meta.path.is_ident("row_major") {
+					matrix_layout_mode = quote!(::core::option::Option::Some(
+						::slang::MatrixLayoutMode::RowMajor
+					));
+				} else if meta.path.is_ident("column_major") {
+					matrix_layout_mode = quote!(::core::option::Option::Some(
+						::slang::MatrixLayoutMode::ColumnMajor
+					));
+				} else {
+					return Err(
+						meta.error("expected `rename`, `skip`, `row_major` or `column_major`")
+					);
+				}
+
+				Ok(())
+			})?;
+		}
+
+		if skip {
+			continue;
+		}
+
+		layouts.push(quote! {
+			::slang::FieldLayout {
+				name: #name,
+				offset: ::core::mem::offset_of!(Self, #ident),
+				size: ::core::mem::size_of::<#ty>(),
+				element_size: <#ty as ::slang::ShaderLayout>::ELEMENT_SIZE,
+				scalar_type: <#ty as ::slang::ShaderLayout>::SCALAR_TYPE,
+				matrix_layout_mode: #matrix_layout_mode,
+				fields: <#ty as ::slang::ShaderLayout>::fields(),
+			}
+		});
+	}
+
+	let ident = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+	Ok(quote! {
+		impl #impl_generics ::slang::ShaderLayout for #ident #ty_generics #where_clause {
+			fn fields() -> ::std::vec::Vec<::slang::FieldLayout> {
+				::std::vec![#(#layouts),*]
+			}
+		}
+	})
+}
+
+fn has_repr_c(input: &DeriveInput) -> syn::Result<bool> {
+	let mut repr_c = false;
+
+	for attr in input
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("repr"))
+	{
+		attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("C") {
+				repr_c = true;
+			} else if meta.input.peek(syn::token::Paren) {
+				// `align(N)` and `packed(N)`.
+				let content;
+				syn::parenthesized!(content in meta.input);
+				content.parse::<TokenStream2>()?;
+			}
+
+			Ok(())
+		})?;
+	}
+
+	Ok(repr_c)
+}
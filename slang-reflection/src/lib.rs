@@ -0,0 +1,343 @@
+//! Reflection data of a Slang program as plain, serializable data, see
+//! `slang::reflection::ProgramReflection`. It doesn't depend on Slang itself, so it can be loaded
+//! by applications that only use precompiled shaders.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// An owned copy of the reflection data of a program.
+///
+/// Unlike the reflection handles it's plain data, so it can be cached, sent to other threads or
+/// saved together with the compiled code and loaded without Slang. Created from a reflection
+/// handle by the `slang` crate.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProgramReflection {
+	pub parameters: Vec<VariableLayoutReflection>,
+	pub entry_points: Vec<EntryPointReflection>,
+	pub global_constant_buffer_binding: u64,
+	pub global_constant_buffer_size: usize,
+	/// Binding ranges of all global parameters together.
+	pub binding_ranges: Vec<BindingRangeReflection>,
+	pub descriptor_sets: Vec<DescriptorSetReflection>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EntryPointReflection {
+	pub name: String,
+	pub name_override: Option<String>,
+	pub stage: Stage,
+	pub compute_thread_group_size: [u64; 3],
+	pub uses_any_sample_rate_input: bool,
+	pub has_default_constant_buffer: bool,
+	pub parameters: Vec<VariableLayoutReflection>,
+	pub result: Option<VariableLayoutReflection>,
+	/// Binding ranges of the entry point parameters together.
+	pub binding_ranges: Vec<BindingRangeReflection>,
+	pub descriptor_sets: Vec<DescriptorSetReflection>,
+	pub user_attributes: Vec<UserAttributeReflection>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VariableLayoutReflection {
+	pub name: Option<String>,
+	/// Where the variable is placed in every category its type uses.
+	pub offsets: Vec<CategoryOffset>,
+	pub semantic_name: Option<String>,
+	pub semantic_index: usize,
+	pub user_attributes: Vec<UserAttributeReflection>,
+	pub type_layout: TypeLayoutReflection,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryOffset {
+	pub category: ParameterCategory,
+	/// In the units of `category`, e.g. bytes for `Uniform` or registers for `ShaderResource`.
+	pub offset: usize,
+	pub space: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TypeLayoutReflection {
+	pub kind: TypeKind,
+	pub name: Option<String>,
+	pub sizes: Vec<CategorySize>,
+	pub fields: Vec<VariableLayoutReflection>,
+	/// `0` for types that aren't arrays.
+	pub element_count: usize,
+	/// The layout of array elements, or of the contents of a buffer or parameter block.
+	pub element_type_layout: Option<Box<TypeLayoutReflection>>,
+	pub row_count: u32,
+	pub column_count: u32,
+	pub scalar_type: ScalarType,
+	pub matrix_layout_mode: MatrixLayoutMode,
+	pub resource_shape: ResourceShape,
+	pub resource_access: ResourceAccess,
+	pub user_attributes: Vec<UserAttributeReflection>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategorySize {
+	pub category: ParameterCategory,
+	pub size: usize,
+	pub alignment: i32,
+	pub stride: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BindingRangeReflection {
+	pub binding_type: BindingType,
+	pub binding_count: i64,
+	pub image_format: ImageFormat,
+	pub is_specializable: bool,
+	pub descriptor_set_index: i64,
+	pub first_descriptor_range_index: i64,
+	pub descriptor_range_count: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DescriptorSetReflection {
+	pub space_offset: i64,
+	pub descriptor_ranges: Vec<DescriptorRangeReflection>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DescriptorRangeReflection {
+	pub index_offset: i64,
+	pub descriptor_count: i64,
+	pub binding_type: BindingType,
+	pub category: ParameterCategory,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UserAttributeReflection {
+	pub name: String,
+	pub arguments: Vec<AttributeArgument>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AttributeArgument {
+	Int(i32),
+	Float(f32),
+	String(String),
+	/// An argument that isn't a literal, e.g. a type.
+	Unknown,
+}
+
+/// Declares a copy of a Slang enum, with the variants and values of the one in `slang`.
+macro_rules! slang_enum {
+	($name:ident { $($variant:ident = $value:expr),* $(,)? }) => {
+		#[repr(u32)]
+		#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+		pub enum $name {
+			$($variant = $value),*
+		}
+	};
+}
+
+slang_enum!(Stage {
+	None = 0,
+	Vertex = 1,
+	Hull = 2,
+	Domain = 3,
+	Geometry = 4,
+	Fragment = 5,
+	Compute = 6,
+	RayGeneration = 7,
+	Intersection = 8,
+	AnyHit = 9,
+	ClosestHit = 10,
+	Miss = 11,
+	Callable = 12,
+	Mesh = 13,
+	Amplification = 14,
+	Count = 15,
+});
+
+slang_enum!(ParameterCategory {
+	None = 0,
+	Mixed = 1,
+	ConstantBuffer = 2,
+	ShaderResource = 3,
+	UnorderedAccess = 4,
+	VaryingInput = 5,
+	VaryingOutput = 6,
+	SamplerState = 7,
+	Uniform = 8,
+	DescriptorTableSlot = 9,
+	SpecializationConstant = 10,
+	PushConstantBuffer = 11,
+	RegisterSpace = 12,
+	Generic = 13,
+	RayPayload = 14,
+	HitAttributes = 15,
+	CallablePayload = 16,
+	ShaderRecord = 17,
+	ExistentialTypeParam = 18,
+	ExistentialObjectParam = 19,
+	SubElementRegisterSpace = 20,
+	Subpass = 21,
+	MetalArgumentBufferElement = 22,
+	MetalAttribute = 23,
+	MetalPayload = 24,
+	Count = 25,
+});
+
+slang_enum!(TypeKind {
+	None = 0,
+	Struct = 1,
+	Array = 2,
+	Matrix = 3,
+	Vector = 4,
+	Scalar = 5,
+	ConstantBuffer = 6,
+	Resource = 7,
+	SamplerState = 8,
+	TextureBuffer = 9,
+	ShaderStorageBuffer = 10,
+	ParameterBlock = 11,
+	GenericTypeParameter = 12,
+	Interface = 13,
+	OutputStream = 14,
+	MeshOutput = 15,
+	Specialized = 16,
+	Feedback = 17,
+	Pointer = 18,
+	DynamicResource = 19,
+	Count = 20,
+});
+
+slang_enum!(ScalarType {
+	None = 0,
+	Void = 1,
+	Bool = 2,
+	Int32 = 3,
+	Uint32 = 4,
+	Int64 = 5,
+	Uint64 = 6,
+	Float16 = 7,
+	Float32 = 8,
+	Float64 = 9,
+	Int8 = 10,
+	Uint8 = 11,
+	Int16 = 12,
+	Uint16 = 13,
+	Intptr = 14,
+	Uintptr = 15,
+});
+
+slang_enum!(MatrixLayoutMode {
+	ModeUnknown = 0,
+	RowMajor = 1,
+	ColumnMajor = 2,
+});
+
+slang_enum!(ResourceShape {
+	SlangResourceBaseShapeMask = 0x0f,
+	SlangResourceNone = 0,
+	SlangTexture1d = 1,
+	SlangTexture2d = 2,
+	SlangTexture3d = 3,
+	SlangTextureCube = 4,
+	SlangTextureBuffer = 5,
+	SlangStructuredBuffer = 6,
+	SlangByteAddressBuffer = 7,
+	SlangResourceUnknown = 8,
+	SlangAccelerationStructure = 9,
+	SlangTextureSubpass = 10,
+	SlangResourceExtShapeMask = 0x1f0,
+	SlangTextureFeedbackFlag = 0x10,
+	SlangTextureShadowFlag = 0x20,
+	SlangTextureArrayFlag = 0x40,
+	SlangTextureMultisampleFlag = 0x80,
+	SlangTexture1dArray = 0x41,
+	SlangTexture2dArray = 0x42,
+	SlangTextureCubeArray = 0x44,
+	SlangTexture2dMultisample = 0x82,
+	SlangTexture2dMultisampleArray = 0xc2,
+	SlangTextureSubpassMultisample = 0x8a,
+});
+
+slang_enum!(ResourceAccess {
+	None = 0,
+	Read = 1,
+	ReadWrite = 2,
+	RasterOrdered = 3,
+	Append = 4,
+	Consume = 5,
+	Write = 6,
+	Feedback = 7,
+	Unknown = 0x7fffffff,
+});
+
+slang_enum!(BindingType {
+	Unknown = 0,
+	Sampler = 1,
+	Texture = 2,
+	ConstantBuffer = 3,
+	ParameterBlock = 4,
+	TypedBuffer = 5,
+	RawBuffer = 6,
+	CombinedTextureSampler = 7,
+	InputRenderTarget = 8,
+	InlineUniformData = 9,
+	RayTracingAccelerationStructure = 10,
+	VaryingInput = 11,
+	VaryingOutput = 12,
+	ExistentialValue = 13,
+	PushConstant = 14,
+	MutableFlag = 0x100,
+	MutableTeture = 0x102,
+	MutableTypedBuffer = 0x105,
+	MutableRawBuffer = 0x106,
+	BaseMask = 0xff,
+	ExtMask = 0xff00,
+});
+
+slang_enum!(ImageFormat {
+	SLANGIMAGEFORMATUnknown = 0,
+	SLANGIMAGEFORMATRgba32f = 1,
+	SLANGIMAGEFORMATRgba16f = 2,
+	SLANGIMAGEFORMATRg32f = 3,
+	SLANGIMAGEFORMATRg16f = 4,
+	SLANGIMAGEFORMATR11fG11fB10f = 5,
+	SLANGIMAGEFORMATR32f = 6,
+	SLANGIMAGEFORMATR16f = 7,
+	SLANGIMAGEFORMATRgba16 = 8,
+	SLANGIMAGEFORMATRgb10A2 = 9,
+	SLANGIMAGEFORMATRgba8 = 10,
+	SLANGIMAGEFORMATRg16 = 11,
+	SLANGIMAGEFORMATRg8 = 12,
+	SLANGIMAGEFORMATR16 = 13,
+	SLANGIMAGEFORMATR8 = 14,
+	SLANGIMAGEFORMATRgba16Snorm = 15,
+	SLANGIMAGEFORMATRgba8Snorm = 16,
+	SLANGIMAGEFORMATRg16Snorm = 17,
+	SLANGIMAGEFORMATRg8Snorm = 18,
+	SLANGIMAGEFORMATR16Snorm = 19,
+	SLANGIMAGEFORMATR8Snorm = 20,
+	SLANGIMAGEFORMATRgba32i = 21,
+	SLANGIMAGEFORMATRgba16i = 22,
+	SLANGIMAGEFORMATRgba8i = 23,
+	SLANGIMAGEFORMATRg32i = 24,
+	SLANGIMAGEFORMATRg16i = 25,
+	SLANGIMAGEFORMATRg8i = 26,
+	SLANGIMAGEFORMATR32i = 27,
+	SLANGIMAGEFORMATR16i = 28,
+	SLANGIMAGEFORMATR8i = 29,
+	SLANGIMAGEFORMATRgba32ui = 30,
+	SLANGIMAGEFORMATRgba16ui = 31,
+	SLANGIMAGEFORMATRgb10A2ui = 32,
+	SLANGIMAGEFORMATRgba8ui = 33,
+	SLANGIMAGEFORMATRg32ui = 34,
+	SLANGIMAGEFORMATRg16ui = 35,
+	SLANGIMAGEFORMATRg8ui = 36,
+	SLANGIMAGEFORMATR32ui = 37,
+	SLANGIMAGEFORMATR16ui = 38,
+	SLANGIMAGEFORMATR8ui = 39,
+	SLANGIMAGEFORMATR64ui = 40,
+	SLANGIMAGEFORMATR64i = 41,
+	SLANGIMAGEFORMATBgra8 = 42,
+	SLANGIMAGEFORMATCount = 43,
+});
@@ -0,0 +1,44 @@
+use crate::{EntryPointReflection, ProgramReflection, Stage};
+
+#[test]
+fn load_program_reflection() {
+	let json = r#"{
+		"parameters": [],
+		"entry_points": [{
+			"name": "main",
+			"name_override": null,
+			"stage": "Compute",
+			"compute_thread_group_size": [64, 1, 1],
+			"uses_any_sample_rate_input": false,
+			"has_default_constant_buffer": false,
+			"parameters": [],
+			"result": null,
+			"binding_ranges": [],
+			"descriptor_sets": [],
+			"user_attributes": []
+		}],
+		"global_constant_buffer_binding": 0,
+		"global_constant_buffer_size": 0,
+		"binding_ranges": [],
+		"descriptor_sets": []
+	}"#;
+
+	let reflection: ProgramReflection = serde_json::from_str(json).unwrap();
+
+	assert_eq!(
+		reflection.entry_points,
+		[EntryPointReflection {
+			name: "main".to_string(),
+			name_override: None,
+			stage: Stage::Compute,
+			compute_thread_group_size: [64, 1, 1],
+			uses_any_sample_rate_input: false,
+			has_default_constant_buffer: false,
+			parameters: Vec::new(),
+			result: None,
+			binding_ranges: Vec::new(),
+			descriptor_sets: Vec::new(),
+			user_attributes: Vec::new(),
+		}]
+	);
+}
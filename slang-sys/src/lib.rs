@@ -21,6 +21,45 @@ pub struct IBlobVtable {
 	pub getBufferSize: unsafe extern "C" fn(*mut c_void) -> usize,
 }
 
+#[repr(C)]
+pub struct IFileSystemVtable {
+	pub _base: ICastableVtable,
+
+	pub loadFile: unsafe extern "C" fn(*mut c_void, path: *const c_char, outBlob: *mut *mut ISlangBlob) -> SlangResult,
+}
+
+#[repr(C)]
+pub struct IFileSystemExtVtable {
+	pub _base: IFileSystemVtable,
+
+	pub getFileUniqueIdentity: unsafe extern "C" fn(*mut c_void, path: *const c_char, outUniqueIdentity: *mut *mut ISlangBlob) -> SlangResult,
+	pub calcCombinedPath: unsafe extern "C" fn(*mut c_void, fromPathType: SlangPathType, fromPath: *const c_char, path: *const c_char, pathOut: *mut *mut ISlangBlob) -> SlangResult,
+	pub getPathType: unsafe extern "C" fn(*mut c_void, path: *const c_char, pathTypeOut: *mut SlangPathType) -> SlangResult,
+	/// `kind` is a `PathKind`.
+	pub getPath: unsafe extern "C" fn(*mut c_void, kind: c_int, path: *const c_char, outPath: *mut *mut ISlangBlob) -> SlangResult,
+	pub clearCache: unsafe extern "C" fn(*mut c_void),
+	pub enumeratePathContents: unsafe extern "C" fn(*mut c_void, path: *const c_char, callback: Option<unsafe extern "C" fn(pathType: SlangPathType, name: *const c_char, userData: *mut c_void)>, userData: *mut c_void) -> SlangResult,
+	/// Returns an `OSPathKind`.
+	pub getOSPathKind: unsafe extern "C" fn(*mut c_void) -> u8,
+}
+
+#[repr(C)]
+pub struct IMutableFileSystemVtable {
+	pub _base: IFileSystemExtVtable,
+
+	pub saveFile: unsafe extern "C" fn(*mut c_void, path: *const c_char, data: *const c_void, size: usize) -> SlangResult,
+	pub saveFileBlob: unsafe extern "C" fn(*mut c_void, path: *const c_char, dataBlob: *mut ISlangBlob) -> SlangResult,
+	pub remove: unsafe extern "C" fn(*mut c_void, path: *const c_char) -> SlangResult,
+	pub createDirectory: unsafe extern "C" fn(*mut c_void, path: *const c_char) -> SlangResult,
+}
+
+#[repr(C)]
+pub struct ISharedLibraryVtable {
+	pub _base: ICastableVtable,
+
+	pub findSymbolAddressByName: unsafe extern "C" fn(*mut c_void, name: *const c_char) -> *mut c_void,
+}
+
 #[repr(C)]
 pub struct IGlobalSessionVtable {
 	pub _base: ISlangUnknown__bindgen_vtable,
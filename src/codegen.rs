@@ -0,0 +1,368 @@
+//! Generates Rust structs that match the uniform layout of Slang types.
+//!
+//! Every struct is `#[repr(C)]` with explicit padding fields and is followed by `const` assertions
+//! on its size and field offsets, so a layout change in a shader fails the Rust build. In a build
+//! script:
+//!
+//! ```ignore
+//! let reflection = linked_program.layout(0).unwrap();
+//! slang::codegen::rerun_if_changed(&module);
+//! slang::codegen::RustStructs::default()
+//!     .program(*reflection)
+//!     .write_to_out_dir("shader_types.rs")
+//!     .unwrap();
+//! ```
+//!
+//! and `include!(concat!(env!("OUT_DIR"), "/shader_types.rs"));` where the types are needed.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::reflection::{Shader, TypeLayout};
+use crate::{MatrixLayoutMode, Module, ParameterCategory, ScalarType, TypeKind};
+
+const KEYWORDS: &[&str] = &[
+	"abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+	"else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+	"loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+	"static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+	"virtual", "where", "while", "yield",
+];
+
+/// Collects the structs to generate. Structs used by other structs are added along with them.
+#[derive(Default)]
+pub struct RustStructs {
+	/// Generated code by struct name, in the order they were added.
+	structs: Vec<(String, String)>,
+	indices: HashMap<String, usize>,
+}
+
+impl RustStructs {
+	/// Adds the struct `layout` describes, it's named after the Slang type.
+	pub fn type_layout(mut self, layout: TypeLayout<'_>) -> Self {
+		self.struct_type(layout, None);
+		self
+	}
+
+	/// Like [`RustStructs::type_layout`], for types that have no name of their own.
+	pub fn named_type_layout(mut self, name: &str, layout: TypeLayout<'_>) -> Self {
+		self.struct_type(layout, Some(name));
+		self
+	}
+
+	/// Adds the contents of every constant buffer, parameter block and structured buffer the
+	/// program uses. Global uniforms outside of a buffer become `GlobalParams`.
+	pub fn program(mut self, shader: Shader<'_>) -> Self {
+		let globals = contents(shader.global_params_type_layout());
+
+		if globals.kind() == TypeKind::Struct && globals.size(ParameterCategory::Uniform) != 0 {
+			self.struct_type(globals, Some("GlobalParams"));
+		}
+
+		for parameter in shader.parameters() {
+			self.parameter_type(parameter.type_layout());
+		}
+
+		for entry_point in shader.entry_points() {
+			for parameter in entry_point.parameters() {
+				self.parameter_type(parameter.type_layout());
+			}
+		}
+
+		self
+	}
+
+	pub fn generate(&self) -> String {
+		let mut code = String::from("// Generated from Slang reflection, do not edit.\n");
+
+		for (_, item) in &self.structs {
+			code.push('\n');
+			code.push_str(item);
+		}
+
+		code
+	}
+
+	/// Writes the generated code to `file_name` in `OUT_DIR`, for use in build scripts.
+	/// The file is only touched when its contents change.
+	pub fn write_to_out_dir(&self, file_name: impl AsRef<Path>) -> io::Result<PathBuf> {
+		let out_dir = std::env::var_os("OUT_DIR").ok_or_else(|| {
+			io::Error::new(
+				io::ErrorKind::NotFound,
+				"OUT_DIR is only set for build scripts",
+			)
+		})?;
+
+		let path = Path::new(&out_dir).join(file_name);
+		let code = self.generate();
+
+		if std::fs::read_to_string(&path).ok().as_deref() != Some(code.as_str()) {
+			std::fs::write(&path, code)?;
+		}
+
+		Ok(path)
+	}
+
+	fn parameter_type(&mut self, layout: TypeLayout<'_>) {
+		match layout.kind() {
+			TypeKind::ConstantBuffer
+			| TypeKind::ParameterBlock
+			| TypeKind::TextureBuffer
+			| TypeKind::ShaderStorageBuffer
+			| TypeKind::Resource => {
+				if let Some(contents) = layout
+					.element_type_layout()
+					.filter(|c| c.kind() == TypeKind::Struct)
+				{
+					self.struct_type(contents, None);
+				}
+			}
+			TypeKind::Array => {
+				if let Some(element) = layout.element_type_layout() {
+					self.parameter_type(element);
+				}
+			}
+			TypeKind::Struct if layout.size(ParameterCategory::Uniform) != 0 => {
+				self.struct_type(layout, None);
+			}
+			_ => {}
+		}
+	}
+
+	/// Returns the name the struct was generated under.
+	fn struct_type(&mut self, layout: TypeLayout<'_>, name: Option<&str>) -> String {
+		let base_name = match name {
+			Some(name) => name.to_string(),
+			None => type_name(layout.name().unwrap_or("Struct")),
+		};
+
+		let mut fields = Vec::new();
+
+		for field in layout.fields() {
+			let field_layout = field.type_layout();
+
+			// Resources and samplers have no uniform data.
+			if field_layout.size(ParameterCategory::Uniform) == 0 {
+				continue;
+			}
+
+			let (ty, size) = self.rust_type(field_layout);
+			let name = field_name(field.variable().and_then(|v| v.name()).unwrap_or("field"));
+			fields.push((field.offset(ParameterCategory::Uniform), name, ty, size));
+		}
+
+		// Arrays of the struct step by the stride, which includes trailing padding.
+		let size = layout
+			.size(ParameterCategory::Uniform)
+			.max(layout.stride(ParameterCategory::Uniform));
+
+		// The same Slang type can have a different layout in another buffer, those get a suffix.
+		let mut name = base_name.clone();
+
+		for suffix in 2.. {
+			let code = struct_code(&name, &fields, size);
+
+			match self.indices.get(&name) {
+				Some(&index) if self.structs[index].1 == code => break,
+				Some(_) => name = format!("{base_name}{suffix}"),
+				None => {
+					self.indices.insert(name.clone(), self.structs.len());
+					self.structs.push((name.clone(), code));
+					break;
+				}
+			}
+		}
+
+		name
+	}
+
+	/// Returns the Rust type and its size.
+	fn rust_type(&mut self, layout: TypeLayout<'_>) -> (String, usize) {
+		let size = layout.size(ParameterCategory::Uniform);
+		let ty = layout.ty();
+
+		match (layout.kind(), scalar_type(ty.scalar_type())) {
+			(TypeKind::Scalar, Some((scalar, scalar_size))) => (scalar.to_string(), scalar_size),
+			(TypeKind::Vector, Some((scalar, scalar_size))) => {
+				let count = ty.element_count();
+				(format!("[{scalar}; {count}]"), scalar_size * count)
+			}
+			(TypeKind::Matrix, Some((scalar, scalar_size))) => {
+				let (rows, columns) = (ty.row_count() as usize, ty.column_count() as usize);
+
+				let (vectors, lanes) = match layout.matrix_layout_mode() {
+					MatrixLayoutMode::RowMajor => (rows, columns),
+					_ => (columns, rows),
+				};
+
+				// Rows or columns are aligned like the matrix, 16 bytes in constant buffers.
+				let alignment = layout.alignment(ParameterCategory::Uniform).max(1) as usize;
+				let stride = (lanes * scalar_size).next_multiple_of(alignment);
+
+				if size < vectors * stride {
+					// D3D constant buffers don't pad the last one, `float3x3` is 44 bytes.
+					let count = size / scalar_size;
+					return (format!("[{scalar}; {count}]"), count * scalar_size);
+				}
+
+				// Padding at the end of each row or column is part of the array.
+				let lanes = stride / scalar_size;
+				(
+					format!("[[{scalar}; {lanes}]; {vectors}]"),
+					vectors * lanes * scalar_size,
+				)
+			}
+			(TypeKind::Array, _) => {
+				let Some(element) = layout.element_type_layout() else {
+					return (format!("[u8; {size}]"), size);
+				};
+				let stride = layout.element_stride(ParameterCategory::Uniform);
+
+				// Unsized arrays at the end of a buffer become zero length arrays.
+				let count = match layout.element_count() {
+					usize::MAX => 0,
+					count => count,
+				};
+
+				let (mut element_type, mut element_size) = self.rust_type(element);
+
+				// Widen scalars and vectors to the stride, e.g. `float[4]` in a constant buffer.
+				let widen = matches!(element.kind(), TypeKind::Scalar | TypeKind::Vector);
+				let widen_scalar = scalar_type(element.ty().scalar_type()).filter(|_| widen);
+
+				// D3D constant buffers don't pad the last element, `float[3]` is 36 bytes. Those
+				// can't be an array of the element type.
+				if size < count * stride {
+					return match widen_scalar {
+						Some((scalar, scalar_size)) => {
+							let count = size / scalar_size;
+							(format!("[{scalar}; {count}]"), count * scalar_size)
+						}
+						None => (format!("[u8; {size}]"), size),
+					};
+				}
+
+				if let Some((scalar, scalar_size)) = widen_scalar {
+					if stride > element_size && stride.is_multiple_of(scalar_size) {
+						element_type = format!("[{scalar}; {}]", stride / scalar_size);
+						element_size = stride;
+					}
+				}
+
+				(format!("[{element_type}; {count}]"), element_size * count)
+			}
+			(TypeKind::Struct, _) => {
+				let name = self.struct_type(layout, None);
+				let size = size.max(layout.stride(ParameterCategory::Uniform));
+				(name, size)
+			}
+			(TypeKind::Pointer, _) => ("u64".to_string(), 8),
+			_ => (format!("[u8; {size}]"), size),
+		}
+	}
+}
+
+/// Tells cargo to rerun the build script when a file `module` was loaded from changes.
+pub fn rerun_if_changed(module: &Module) {
+	for path in module.dependency_file_paths() {
+		println!("cargo:rerun-if-changed={}", path.display());
+	}
+}
+
+fn struct_code(name: &str, fields: &[(usize, String, String, usize)], size: usize) -> String {
+	let mut code = String::new();
+	let mut offset = 0;
+	let mut padding = 0;
+
+	writeln!(code, "#[repr(C)]").unwrap();
+	writeln!(code, "#[derive(Clone, Copy, Debug, PartialEq)]").unwrap();
+	writeln!(code, "pub struct {name} {{").unwrap();
+
+	for (field_offset, field_name, ty, field_size) in fields {
+		// Overlapping fields can't be represented, the offset assertion reports them.
+		if *field_offset > offset {
+			writeln!(
+				code,
+				"    pub _pad{padding}: [u8; {}],",
+				field_offset - offset
+			)
+			.unwrap();
+			padding += 1;
+		}
+
+		writeln!(code, "    pub {field_name}: {ty},").unwrap();
+		offset = offset.max(field_offset + field_size);
+	}
+
+	if size > offset {
+		writeln!(code, "    pub _pad{padding}: [u8; {}],", size - offset).unwrap();
+	}
+
+	writeln!(code, "}}").unwrap();
+	writeln!(code).unwrap();
+	writeln!(
+		code,
+		"const _: () = assert!(std::mem::size_of::<{name}>() == {size});"
+	)
+	.unwrap();
+
+	for (field_offset, field_name, _, _) in fields {
+		writeln!(
+			code,
+			"const _: () = assert!(std::mem::offset_of!({name}, {field_name}) == {field_offset});"
+		)
+		.unwrap();
+	}
+
+	code
+}
+
+/// The buffer contents for constant buffers and parameter blocks, `layout` itself otherwise.
+fn contents(layout: TypeLayout<'_>) -> TypeLayout<'_> {
+	match layout.kind() {
+		TypeKind::ConstantBuffer | TypeKind::ParameterBlock => {
+			layout.element_type_layout().unwrap_or(layout)
+		}
+		_ => layout,
+	}
+}
+
+fn scalar_type(scalar_type: ScalarType) -> Option<(&'static str, usize)> {
+	Some(match scalar_type {
+		// Booleans are 32 bits in buffers on every GPU target.
+		ScalarType::Bool => ("u32", 4),
+		ScalarType::Int8 => ("i8", 1),
+		ScalarType::Uint8 => ("u8", 1),
+		ScalarType::Int16 => ("i16", 2),
+		ScalarType::Uint16 => ("u16", 2),
+		// Rust has no stable half type, the bits are stored as is.
+		ScalarType::Float16 => ("u16", 2),
+		ScalarType::Int32 => ("i32", 4),
+		ScalarType::Uint32 => ("u32", 4),
+		ScalarType::Float32 => ("f32", 4),
+		ScalarType::Int64 => ("i64", 8),
+		ScalarType::Uint64 => ("u64", 8),
+		ScalarType::Float64 => ("f64", 8),
+		_ => return None,
+	})
+}
+
+/// Turns names of specialized generics like `Foo<float>` into `Foo_float`.
+fn type_name(name: &str) -> String {
+	let name: String = name
+		.chars()
+		.map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+		.collect();
+
+	name.trim_end_matches('_').to_string()
+}
+
+fn field_name(name: &str) -> String {
+	if KEYWORDS.contains(&name) {
+		format!("r#{name}")
+	} else {
+		name.to_string()
+	}
+}
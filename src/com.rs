@@ -0,0 +1,157 @@
+//! Support for implementing Slang COM interfaces in Rust.
+
+use std::ffi::c_void;
+use std::ptr::{null_mut, NonNull};
+use std::sync::atomic::{fence, AtomicU32, Ordering};
+
+use crate::{sys, Blob, IUnknown, Interface, E_NO_INTERFACE, UUID};
+
+/// `ISlangCastable`, which has no wrapper of its own.
+pub(crate) const CASTABLE_IID: UUID = crate::uuid(
+	0x87ede0e1,
+	0x4852,
+	0x44b0,
+	[0x8b, 0xf2, 0xcb, 0x31, 0x87, 0x4d, 0xe2, 0x39],
+);
+
+/// A Rust type that is handed to Slang as a COM object.
+///
+/// # Safety
+///
+/// `VTABLE` must start with the `ISlangUnknown` functions of [`ComObject<Self>`] and every
+/// interface in `IIDS` must be a prefix of it.
+pub(crate) unsafe trait ComImpl: Sized + 'static {
+	type Vtable: 'static;
+	const VTABLE: &'static Self::Vtable;
+	/// The interfaces the object can be queried for, besides `ISlangUnknown`.
+	const IIDS: &'static [UUID];
+}
+
+/// The in-memory layout of a Rust implemented COM object, the vtable pointer must come first.
+#[repr(C)]
+pub(crate) struct ComObject<T: ComImpl> {
+	vtable: &'static T::Vtable,
+	ref_count: AtomicU32,
+	value: T,
+}
+
+impl<T: ComImpl> ComObject<T> {
+	/// Moves `value` to the heap, the returned reference is the only one.
+	pub(crate) fn create(value: T) -> IUnknown {
+		let object = Box::new(ComObject {
+			vtable: T::VTABLE,
+			ref_count: AtomicU32::new(1),
+			value,
+		});
+
+		IUnknown(NonNull::new(Box::into_raw(object) as *mut c_void).unwrap())
+	}
+
+	/// # Safety
+	///
+	/// `this` must point to a live `ComObject<T>`.
+	pub(crate) unsafe fn value<'a, P>(this: *mut P) -> &'a T {
+		&(*(this as *const Self)).value
+	}
+
+	pub(crate) const fn unknown_vtable() -> sys::ISlangUnknown__bindgen_vtable {
+		sys::ISlangUnknown__bindgen_vtable {
+			ISlangUnknown_queryInterface: Self::query_interface,
+			ISlangUnknown_addRef: Self::add_ref,
+			ISlangUnknown_release: Self::release,
+		}
+	}
+
+	pub(crate) const fn castable_vtable() -> sys::ICastableVtable {
+		sys::ICastableVtable {
+			_base: Self::unknown_vtable(),
+			castAs: Self::cast_as,
+		}
+	}
+
+	fn supports(iid: &UUID) -> bool {
+		uuid_eq(iid, &IUnknown::IID) || T::IIDS.iter().any(|supported| uuid_eq(iid, supported))
+	}
+
+	unsafe extern "C" fn query_interface(
+		this: *mut sys::ISlangUnknown,
+		uuid: *const UUID,
+		out_object: *mut *mut c_void,
+	) -> sys::SlangResult {
+		if !Self::supports(&*uuid) {
+			*out_object = null_mut();
+			return E_NO_INTERFACE;
+		}
+
+		Self::add_ref(this);
+		*out_object = this as *mut c_void;
+		0
+	}
+
+	unsafe extern "C" fn add_ref(this: *mut sys::ISlangUnknown) -> u32 {
+		(*(this as *const Self))
+			.ref_count
+			.fetch_add(1, Ordering::Relaxed)
+			+ 1
+	}
+
+	unsafe extern "C" fn release(this: *mut sys::ISlangUnknown) -> u32 {
+		let count = (*(this as *const Self))
+			.ref_count
+			.fetch_sub(1, Ordering::Release)
+			- 1;
+
+		if count == 0 {
+			fence(Ordering::Acquire);
+			drop(Box::from_raw(this as *mut Self));
+		}
+
+		count
+	}
+
+	// Unlike `queryInterface`, `castAs` doesn't add a reference.
+	unsafe extern "C" fn cast_as(this: *mut c_void, guid: *const UUID) -> *mut c_void {
+		if Self::supports(&*guid) {
+			this
+		} else {
+			null_mut()
+		}
+	}
+}
+
+fn uuid_eq(a: &UUID, b: &UUID) -> bool {
+	(a.data1, a.data2, a.data3, a.data4) == (b.data1, b.data2, b.data3, b.data4)
+}
+
+/// Hands ownership of `value` to a Slang out parameter.
+pub(crate) unsafe fn write_out<I: Interface, T>(out: *mut *mut T, value: I) {
+	*out = value.as_raw();
+	std::mem::forget(value);
+}
+
+/// A blob that owns its bytes on the Rust side.
+pub(crate) struct VecBlob(pub(crate) Vec<u8>);
+
+unsafe impl ComImpl for VecBlob {
+	type Vtable = sys::IBlobVtable;
+	const VTABLE: &'static sys::IBlobVtable = &sys::IBlobVtable {
+		_base: ComObject::<Self>::unknown_vtable(),
+		getBufferPointer: Self::buffer_pointer,
+		getBufferSize: Self::buffer_size,
+	};
+	const IIDS: &'static [UUID] = &[Blob::IID];
+}
+
+impl VecBlob {
+	pub(crate) fn into_blob(self) -> Blob {
+		Blob(ComObject::create(self))
+	}
+
+	unsafe extern "C" fn buffer_pointer(this: *mut c_void) -> *const c_void {
+		ComObject::<Self>::value(this).0.as_ptr() as *const c_void
+	}
+
+	unsafe extern "C" fn buffer_size(this: *mut c_void) -> usize {
+		ComObject::<Self>::value(this).0.len()
+	}
+}
@@ -0,0 +1,228 @@
+use std::borrow::Cow;
+
+use crate::Blob;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+	Note,
+	Warning,
+	Error,
+	Fatal,
+	Internal,
+}
+
+impl Severity {
+	// Longest names first, so "fatal error" isn't matched as "error".
+	const NAMES: [(&'static str, Severity); 5] = [
+		("internal error", Severity::Internal),
+		("fatal error", Severity::Fatal),
+		("warning", Severity::Warning),
+		("error", Severity::Error),
+		("note", Severity::Note),
+	];
+
+	pub fn is_error(self) -> bool {
+		self >= Severity::Error
+	}
+}
+
+/// A single message from the compiler output, e.g. `file.slang(12): error 30015: undefined identifier 'x'.`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+	pub severity: Severity,
+	pub code: Option<u32>,
+	pub file: Option<String>,
+	pub line: Option<u32>,
+	/// Recovered from the `^` marker below the source excerpt when the location doesn't include it.
+	pub column: Option<u32>,
+	pub message: String,
+}
+
+impl Diagnostic {
+	/// Parses all diagnostics from the compiler output, skipping source excerpts and other lines.
+	pub fn parse(text: &str) -> Vec<Diagnostic> {
+		let mut diagnostics = Vec::<Diagnostic>::new();
+
+		for line in text.lines() {
+			if let Some(diagnostic) = Self::parse_line(line) {
+				diagnostics.push(diagnostic);
+			} else if let Some(column) = caret_column(line) {
+				if let Some(last) = diagnostics.last_mut() {
+					last.column = last.column.or(Some(column));
+				}
+			}
+		}
+
+		diagnostics
+	}
+
+	fn parse_line(line: &str) -> Option<Diagnostic> {
+		let line = line.trim_end();
+
+		// Try every `): ` as the end of the location, file paths can contain parentheses themselves.
+		for (end, _) in line.match_indices("): ") {
+			let location = &line[..end];
+			let Some(open) = location.rfind('(') else {
+				continue;
+			};
+
+			let Some((line_number, column)) = parse_line_column(&location[open + 1..]) else {
+				continue;
+			};
+
+			if let Some(mut diagnostic) = Self::parse_message(&line[end + 3..]) {
+				diagnostic.file = Some(location[..open].to_string());
+				diagnostic.line = Some(line_number);
+				diagnostic.column = column;
+				return Some(diagnostic);
+			}
+		}
+
+		Self::parse_message(line)
+	}
+
+	/// Parses `error 12345: message`, the part after the location.
+	fn parse_message(text: &str) -> Option<Diagnostic> {
+		let (severity, rest) = Severity::NAMES.iter().find_map(|(name, severity)| {
+			let rest = text.strip_prefix(name)?;
+			rest.starts_with([' ', ':']).then_some((*severity, rest))
+		})?;
+
+		let (code, message) = rest.split_once(':')?;
+		let code = code.trim();
+
+		let code = if code.is_empty() {
+			None
+		} else {
+			Some(code.parse().ok()?)
+		};
+
+		Some(Diagnostic {
+			severity,
+			code,
+			file: None,
+			line: None,
+			column: None,
+			message: message.trim().to_string(),
+		})
+	}
+}
+
+/// Parses `12` or `12, 5` from a location.
+fn parse_line_column(text: &str) -> Option<(u32, Option<u32>)> {
+	match text.split_once(',') {
+		Some((line, column)) => {
+			Some((line.trim().parse().ok()?, Some(column.trim().parse().ok()?)))
+		}
+		None => Some((text.trim().parse().ok()?, None)),
+	}
+}
+
+/// Returns the 1-based column of a `^` marker line, as printed below source excerpts.
+fn caret_column(line: &str) -> Option<u32> {
+	let trimmed = line.trim();
+	let is_marker = !trimmed.is_empty() && trimmed.chars().all(|c| matches!(c, '^' | '~' | ' '));
+
+	if !is_marker {
+		return None;
+	}
+
+	line.chars().position(|c| c == '^').map(|i| i as u32 + 1)
+}
+
+/// The diagnostic output of a compiler operation, both as raw text and parsed.
+#[derive(Clone)]
+pub struct Diagnostics {
+	blob: Blob,
+	diagnostics: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+	pub(crate) fn new(blob: Blob) -> Self {
+		let diagnostics = Diagnostic::parse(&String::from_utf8_lossy(blob.as_slice()));
+		Self { blob, diagnostics }
+	}
+
+	pub fn blob(&self) -> &Blob {
+		&self.blob
+	}
+
+	/// The raw compiler output, with invalid UTF-8 replaced.
+	pub fn text(&self) -> Cow<'_, str> {
+		String::from_utf8_lossy(self.blob.as_slice())
+	}
+
+	pub fn as_slice(&self) -> &[Diagnostic] {
+		&self.diagnostics
+	}
+
+	pub fn iter(&self) -> std::slice::Iter<'_, Diagnostic> {
+		self.diagnostics.iter()
+	}
+
+	pub fn errors(&self) -> impl Iterator<Item = &Diagnostic> {
+		self.iter().filter(|d| d.severity.is_error())
+	}
+
+	pub fn warnings(&self) -> impl Iterator<Item = &Diagnostic> {
+		self.iter().filter(|d| d.severity == Severity::Warning)
+	}
+}
+
+impl<'a> IntoIterator for &'a Diagnostics {
+	type Item = &'a Diagnostic;
+	type IntoIter = std::slice::Iter<'a, Diagnostic>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl std::fmt::Debug for Diagnostics {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.text())
+	}
+}
+
+impl std::fmt::Display for Diagnostics {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Debug::fmt(self, f)
+	}
+}
+
+/// The result of a successful compiler operation, together with the warnings it reported.
+pub struct Compiled<T> {
+	pub value: T,
+	pub diagnostics: Option<Diagnostics>,
+}
+
+impl<T> Compiled<T> {
+	pub fn into_value(self) -> T {
+		self.value
+	}
+
+	pub fn warnings(&self) -> impl Iterator<Item = &Diagnostic> {
+		self.diagnostics.iter().flat_map(|d| d.warnings())
+	}
+
+	pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Compiled<U> {
+		Compiled {
+			value: f(self.value),
+			diagnostics: self.diagnostics,
+		}
+	}
+}
+
+impl<T> std::ops::Deref for Compiled<T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.value
+	}
+}
+
+impl<T> std::ops::DerefMut for Compiled<T> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.value
+	}
+}
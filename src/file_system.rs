@@ -0,0 +1,356 @@
+use std::ffi::{c_char, c_int, c_void, CStr};
+use std::io;
+use std::ptr::{null_mut, NonNull};
+
+use crate::com::{write_out, ComImpl, ComObject, VecBlob, CASTABLE_IID};
+use crate::{
+	c_string, result_from_blob, sys, uuid, Blob, IUnknown, Interface, PathType, Result,
+	E_CANNOT_OPEN, E_INVALID_ARG, E_NOT_FOUND, E_NOT_IMPLEMENTED, UUID,
+};
+
+const FILE_SYSTEM_IID: UUID = uuid(
+	0x003a09fc,
+	0x3a4d,
+	0x4ba0,
+	[0xad, 0x60, 0x1f, 0xd8, 0x63, 0xa9, 0x15, 0xab],
+);
+
+/// A file system Slang loads modules and included files from, see [`crate::SessionDesc::file_system`].
+///
+/// Paths use `/` as separator and are relative to the root of the file system unless they start
+/// with one. Only [`FileSystem::load_file`] is required.
+pub trait FileSystem: 'static {
+	/// Returns [`io::ErrorKind::NotFound`] if there is no such file, so the next search path is tried.
+	fn load_file(&self, path: &str) -> io::Result<Vec<u8>>;
+
+	/// Slang uses the canonical path to tell whether two paths refer to the same file.
+	/// The default normalizes separators and removes `.` and `..` components.
+	fn canonical_path(&self, path: &str) -> io::Result<String> {
+		Ok(simplify_path(path))
+	}
+
+	/// The default treats every path that can be loaded as a file.
+	fn path_type(&self, path: &str) -> io::Result<PathType> {
+		self.load_file(path).map(|_| PathType::File)
+	}
+}
+
+/// A [`FileSystem`] wrapped in a COM object that can be passed to Slang.
+/// Sessions created with it keep it alive on their own.
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct CustomFileSystem(IUnknown);
+
+unsafe impl Interface for CustomFileSystem {
+	type Vtable = sys::IFileSystemExtVtable;
+	const IID: UUID = uuid(
+		0x5fb632d2,
+		0x979d,
+		0x4481,
+		[0x9f, 0xee, 0x66, 0x3c, 0x3f, 0x14, 0x49, 0xe1],
+	);
+}
+
+impl CustomFileSystem {
+	pub fn new(file_system: impl FileSystem) -> Self {
+		Self(ComObject::create(FileSystemImpl(Box::new(file_system))))
+	}
+
+	pub(crate) fn as_ptr(&self) -> *mut sys::ISlangFileSystem {
+		unsafe { self.as_raw() }
+	}
+}
+
+struct FileSystemImpl(Box<dyn FileSystem>);
+
+unsafe impl ComImpl for FileSystemImpl {
+	type Vtable = sys::IFileSystemExtVtable;
+	const VTABLE: &'static sys::IFileSystemExtVtable = &sys::IFileSystemExtVtable {
+		_base: sys::IFileSystemVtable {
+			_base: ComObject::<Self>::castable_vtable(),
+			loadFile: Self::load_file,
+		},
+		getFileUniqueIdentity: Self::file_unique_identity,
+		calcCombinedPath: Self::calc_combined_path,
+		getPathType: Self::path_type,
+		getPath: Self::path,
+		clearCache: Self::clear_cache,
+		enumeratePathContents: Self::enumerate_path_contents,
+		getOSPathKind: Self::os_path_kind,
+	};
+	const IIDS: &'static [UUID] = &[CASTABLE_IID, FILE_SYSTEM_IID, CustomFileSystem::IID];
+}
+
+impl FileSystemImpl {
+	unsafe fn get<'a>(this: *mut c_void) -> &'a dyn FileSystem {
+		&*ComObject::<Self>::value(this).0
+	}
+
+	unsafe extern "C" fn load_file(
+		this: *mut c_void,
+		path: *const c_char,
+		out_blob: *mut *mut sys::ISlangBlob,
+	) -> sys::SlangResult {
+		let Some(path) = path_arg(path) else {
+			return E_INVALID_ARG;
+		};
+
+		write_result(out_blob, Self::get(this).load_file(path))
+	}
+
+	unsafe extern "C" fn file_unique_identity(
+		this: *mut c_void,
+		path: *const c_char,
+		out_unique_identity: *mut *mut sys::ISlangBlob,
+	) -> sys::SlangResult {
+		let Some(path) = path_arg(path) else {
+			return E_INVALID_ARG;
+		};
+
+		let identity = Self::get(this).canonical_path(path);
+		write_result(out_unique_identity, identity.map(String::into_bytes))
+	}
+
+	unsafe extern "C" fn calc_combined_path(
+		_this: *mut c_void,
+		from_path_type: sys::SlangPathType,
+		from_path: *const c_char,
+		path: *const c_char,
+		path_out: *mut *mut sys::ISlangBlob,
+	) -> sys::SlangResult {
+		let (Some(from_path), Some(path)) = (path_arg(from_path), path_arg(path)) else {
+			return E_INVALID_ARG;
+		};
+
+		let combined = combine_paths(from_path_type, from_path, path);
+		write_result(path_out, Ok(combined.into_bytes()))
+	}
+
+	unsafe extern "C" fn path_type(
+		this: *mut c_void,
+		path: *const c_char,
+		path_type_out: *mut sys::SlangPathType,
+	) -> sys::SlangResult {
+		let Some(path) = path_arg(path) else {
+			return E_INVALID_ARG;
+		};
+
+		match Self::get(this).path_type(path) {
+			Ok(path_type) => {
+				*path_type_out = path_type;
+				0
+			}
+			Err(error) => error_code(&error),
+		}
+	}
+
+	unsafe extern "C" fn path(
+		this: *mut c_void,
+		kind: c_int,
+		path: *const c_char,
+		out_path: *mut *mut sys::ISlangBlob,
+	) -> sys::SlangResult {
+		let Some(path) = path_arg(path) else {
+			return E_INVALID_ARG;
+		};
+
+		// `PathKind::Canonical`, the simplified and display paths are the same.
+		let path = if kind == 1 {
+			Self::get(this).canonical_path(path)
+		} else {
+			Ok(simplify_path(path))
+		};
+
+		write_result(out_path, path.map(String::into_bytes))
+	}
+
+	unsafe extern "C" fn clear_cache(_this: *mut c_void) {}
+
+	unsafe extern "C" fn enumerate_path_contents(
+		_this: *mut c_void,
+		_path: *const c_char,
+		_callback: Option<unsafe extern "C" fn(sys::SlangPathType, *const c_char, *mut c_void)>,
+		_user_data: *mut c_void,
+	) -> sys::SlangResult {
+		E_NOT_IMPLEMENTED
+	}
+
+	// `OSPathKind::None`, paths can't be passed to the operating system.
+	unsafe extern "C" fn os_path_kind(_this: *mut c_void) -> u8 {
+		0
+	}
+}
+
+/// The files produced by compiling an entry point, see [`crate::ComponentType::result_file_system`].
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct ResultFileSystem(pub(crate) IUnknown);
+
+unsafe impl Interface for ResultFileSystem {
+	type Vtable = sys::IMutableFileSystemVtable;
+	const IID: UUID = uuid(
+		0xa058675c,
+		0x1d65,
+		0x452a,
+		[0x84, 0x58, 0xcc, 0xde, 0xd1, 0x42, 0x71, 0x05],
+	);
+}
+
+impl ResultFileSystem {
+	pub fn load_file(&self, path: &str) -> Result<Blob> {
+		let path = c_string(path)?;
+		let mut blob = null_mut();
+
+		result_from_blob(
+			unsafe {
+				(self.vtable()._base._base.loadFile)(self.as_raw(), path.as_ptr(), &mut blob)
+			},
+			null_mut(),
+		)?;
+
+		Ok(Blob(IUnknown(NonNull::new(blob as *mut _).unwrap())))
+	}
+
+	pub fn path_type(&self, path: &str) -> Result<PathType> {
+		let path = c_string(path)?;
+		let mut path_type = PathType::File;
+
+		result_from_blob(
+			unsafe {
+				(self.vtable()._base.getPathType)(self.as_raw(), path.as_ptr(), &mut path_type)
+			},
+			null_mut(),
+		)?;
+
+		Ok(path_type)
+	}
+
+	/// The names of the files and directories in a directory, `"."` is the root.
+	pub fn read_dir(&self, path: &str) -> Result<Vec<(String, PathType)>> {
+		unsafe extern "C" fn add_entry(
+			path_type: sys::SlangPathType,
+			name: *const c_char,
+			user_data: *mut c_void,
+		) {
+			let entries = &mut *(user_data as *mut Vec<(String, PathType)>);
+			let name = CStr::from_ptr(name).to_string_lossy().into_owned();
+			entries.push((name, path_type));
+		}
+
+		let path = c_string(path)?;
+		let mut entries = Vec::new();
+
+		result_from_blob(
+			unsafe {
+				(self.vtable()._base.enumeratePathContents)(
+					self.as_raw(),
+					path.as_ptr(),
+					Some(add_entry),
+					&mut entries as *mut Vec<_> as *mut c_void,
+				)
+			},
+			null_mut(),
+		)?;
+
+		Ok(entries)
+	}
+
+	/// The paths of all files, relative to the root.
+	pub fn files(&self) -> Result<Vec<String>> {
+		let mut files = Vec::new();
+		let mut directories = vec![String::new()];
+
+		while let Some(directory) = directories.pop() {
+			let path = if directory.is_empty() {
+				"."
+			} else {
+				&directory
+			};
+
+			for (name, path_type) in self.read_dir(path)? {
+				let path = if directory.is_empty() {
+					name
+				} else {
+					format!("{directory}/{name}")
+				};
+
+				match path_type {
+					PathType::Directory => directories.push(path),
+					PathType::File => files.push(path),
+				}
+			}
+		}
+
+		files.sort();
+		Ok(files)
+	}
+}
+
+unsafe fn path_arg<'a>(path: *const c_char) -> Option<&'a str> {
+	if path.is_null() {
+		None
+	} else {
+		CStr::from_ptr(path).to_str().ok()
+	}
+}
+
+unsafe fn write_result(
+	out: *mut *mut sys::ISlangBlob,
+	result: io::Result<Vec<u8>>,
+) -> sys::SlangResult {
+	match result {
+		Ok(data) => {
+			write_out(out, VecBlob(data).into_blob());
+			0
+		}
+		Err(error) => error_code(&error),
+	}
+}
+
+fn error_code(error: &io::Error) -> sys::SlangResult {
+	match error.kind() {
+		io::ErrorKind::NotFound => E_NOT_FOUND,
+		io::ErrorKind::Unsupported => E_NOT_IMPLEMENTED,
+		io::ErrorKind::InvalidInput => E_INVALID_ARG,
+		_ => E_CANNOT_OPEN,
+	}
+}
+
+/// Resolves `path` relative to `from_path`, which is either a file or the directory it's in.
+fn combine_paths(from_path_type: PathType, from_path: &str, path: &str) -> String {
+	let directory = match from_path_type {
+		PathType::Directory => from_path,
+		PathType::File => from_path
+			.rsplit_once(['/', '\\'])
+			.map_or("", |(directory, _)| directory),
+	};
+
+	if directory.is_empty() || path.starts_with(['/', '\\']) {
+		simplify_path(path)
+	} else {
+		simplify_path(&format!("{directory}/{path}"))
+	}
+}
+
+/// Normalizes separators to `/` and removes `.` and `..` components where possible.
+fn simplify_path(path: &str) -> String {
+	let mut components = Vec::new();
+
+	for component in path.split(['/', '\\']) {
+		match component {
+			"" | "." => {}
+			".." if components.last().is_some_and(|last| *last != "..") => {
+				components.pop();
+			}
+			_ => components.push(component),
+		}
+	}
+
+	let simplified = components.join("/");
+
+	if path.starts_with(['/', '\\']) {
+		format!("/{simplified}")
+	} else {
+		simplified
+	}
+}
@@ -0,0 +1,179 @@
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::{Blob, Compiled, ComponentType, Diagnostics, Downcast, Error, Result, Session};
+
+/// A freshly linked program, handed to the reload callback of [`ShaderWatcher::watch`].
+pub struct Program {
+	pub linked: ComponentType,
+	/// Code for each watched entry point, in the order they were passed to [`ShaderWatcher::watch`].
+	pub code: Vec<Blob>,
+	/// Warnings reported while loading, linking and generating code.
+	pub diagnostics: Vec<Diagnostics>,
+}
+
+struct Dependency {
+	path: PathBuf,
+	modified: Option<SystemTime>,
+}
+
+impl Dependency {
+	fn new(path: PathBuf) -> Self {
+		let modified = modified_time(&path);
+		Self { path, modified }
+	}
+}
+
+struct WatchedProgram {
+	module: String,
+	entry_points: Vec<String>,
+	target: i64,
+	dependencies: Vec<Dependency>,
+	on_reload: Box<dyn FnMut(Result<Program>)>,
+}
+
+/// Tracks the files loaded modules depend on and relinks programs when one of them changes.
+///
+/// The watcher polls file modification times, call [`ShaderWatcher::poll`] once per frame or on a
+/// timer. Slang caches modules per session, so a new session is created before reloading.
+pub struct ShaderWatcher {
+	create_session: Box<dyn FnMut() -> Option<Session>>,
+	session: Session,
+	programs: Vec<WatchedProgram>,
+}
+
+impl ShaderWatcher {
+	pub fn new(mut create_session: impl FnMut() -> Option<Session> + 'static) -> Option<Self> {
+		let session = create_session()?;
+
+		Some(Self {
+			create_session: Box::new(create_session),
+			session,
+			programs: Vec::new(),
+		})
+	}
+
+	pub fn session(&self) -> &Session {
+		&self.session
+	}
+
+	/// Loads `module` and links it together with `entry_points`.
+	/// Whenever one of the files the module depends on changes, the program is relinked and
+	/// `on_reload` receives either the new program or the diagnostics.
+	///
+	/// Dependencies are only known after the module loaded successfully once, so a program that
+	/// fails to load here isn't watched.
+	pub fn watch(
+		&mut self,
+		module: &str,
+		entry_points: &[&str],
+		target: i64,
+		on_reload: impl FnMut(Result<Program>) + 'static,
+	) -> Result<Program> {
+		let mut program = WatchedProgram {
+			module: module.to_string(),
+			entry_points: entry_points.iter().map(|name| name.to_string()).collect(),
+			target,
+			dependencies: Vec::new(),
+			on_reload: Box::new(on_reload),
+		};
+
+		let result = program.load(&self.session)?;
+		self.programs.push(program);
+		Ok(result)
+	}
+
+	/// Checks all watched files for changes and reloads the affected programs.
+	/// Returns the number of programs that were reloaded.
+	///
+	/// The old session would return the cached modules, so if no new session can be created
+	/// nothing is reloaded and the changes are picked up by the next call.
+	///
+	/// Modification times are recorded before reloading, so a program that fails to reload is
+	/// only retried once one of its files changes again, and edits made while it reloads aren't
+	/// missed.
+	pub fn poll(&mut self) -> usize {
+		let changed = self
+			.programs
+			.iter()
+			.map(|program| program.has_changed())
+			.collect::<Vec<_>>();
+
+		if !changed.contains(&true) {
+			return 0;
+		}
+
+		let Some(session) = (self.create_session)() else {
+			return 0;
+		};
+		self.session = session;
+
+		let mut count = 0;
+
+		for (program, _) in self.programs.iter_mut().zip(changed).filter(|(_, c)| *c) {
+			let result = program.load(&self.session);
+			(program.on_reload)(result);
+			count += 1;
+		}
+
+		count
+	}
+}
+
+impl WatchedProgram {
+	fn has_changed(&self) -> bool {
+		self.dependencies
+			.iter()
+			.any(|dependency| modified_time(&dependency.path) != dependency.modified)
+	}
+
+	fn load(&mut self, session: &Session) -> Result<Program> {
+		// Refresh the timestamps up front, a file saved during the build is seen by the next poll.
+		for dependency in &mut self.dependencies {
+			dependency.modified = modified_time(&dependency.path);
+		}
+
+		let mut diagnostics = Vec::new();
+		let module = collect(session.load_module(&self.module)?, &mut diagnostics);
+
+		self.dependencies = module
+			.dependency_file_paths()
+			.map(|path| Dependency::new(path.into_owned()))
+			.collect();
+
+		let mut components = vec![module.downcast().clone()];
+
+		for name in &self.entry_points {
+			let entry_point = module
+				.find_entry_point_by_name(name)
+				.ok_or_else(|| Error::EntryPointNotFound(name.clone()))?;
+			components.push(entry_point.downcast().clone());
+		}
+
+		let composite = session.create_composite_component_type(&components)?;
+		let linked = collect(composite.link()?, &mut diagnostics);
+		diagnostics.extend(composite.diagnostics);
+
+		let mut code = Vec::new();
+
+		for index in 0..self.entry_points.len() as i64 {
+			let blob = linked.entry_point_code(index, self.target)?;
+			code.push(collect(blob, &mut diagnostics));
+		}
+
+		Ok(Program {
+			linked,
+			code,
+			diagnostics,
+		})
+	}
+}
+
+fn collect<T>(compiled: Compiled<T>, diagnostics: &mut Vec<Diagnostics>) -> T {
+	diagnostics.extend(compiled.diagnostics);
+	compiled.value
+}
+
+fn modified_time(path: &std::path::Path) -> Option<SystemTime> {
+	std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
@@ -1,20 +1,52 @@
+pub mod codegen;
+mod com;
+mod diagnostics;
+mod file_system;
 pub mod reflection;
+mod shader_cursor;
+mod shader_layout;
+mod shared_library;
+
+#[cfg(feature = "hot-reload")]
+pub mod hot_reload;
+
+#[cfg(feature = "ash")]
+pub mod vulkan;
+#[cfg(feature = "wgpu")]
+pub mod webgpu;
 
 #[cfg(test)]
 mod tests;
 
+use std::borrow::Cow;
 use std::ffi::{CStr, CString};
 use std::marker::PhantomData;
+use std::path::Path;
 use std::ptr::{null, null_mut};
 
 use slang_sys as sys;
 
+// Lets code generated by the derive macros refer to `::slang` inside this crate.
+#[cfg(feature = "derive")]
+extern crate self as slang;
+
+pub use diagnostics::{Compiled, Diagnostic, Diagnostics, Severity};
+pub use file_system::{CustomFileSystem, FileSystem, ResultFileSystem};
+pub use shader_cursor::{CursorError, ResourceBinding, ShaderCursor, ShaderData};
+pub use shader_layout::{FieldLayout, LayoutMismatch, ShaderLayout};
+pub use shared_library::{ComputeKernel, ComputeVaryingInput, SharedLibrary};
+#[cfg(feature = "derive")]
+pub use slang_derive::ShaderLayout;
+#[cfg(feature = "serde")]
+pub use slang_reflection;
 pub use sys::{
-	slang_CompilerOptionName as CompilerOptionName, SlangBindingType as BindingType,
+	slang_CompilerOptionName as CompilerOptionName, slang_ContainerType as ContainerType,
+	slang_LayoutRules as LayoutRules, SlangBindingType as BindingType,
 	SlangCompileTarget as CompileTarget, SlangDebugInfoLevel as DebugInfoLevel,
 	SlangFloatingPointMode as FloatingPointMode, SlangImageFormat as ImageFormat,
 	SlangLineDirectiveMode as LineDirectiveMode, SlangMatrixLayoutMode as MatrixLayoutMode,
 	SlangOptimizationLevel as OptimizationLevel, SlangParameterCategory as ParameterCategory,
+	SlangPathType as PathType, SlangResourceAccess as ResourceAccess,
 	SlangResourceShape as ResourceShape, SlangScalarType as ScalarType,
 	SlangSourceLanguage as SourceLanguage, SlangStage as Stage, SlangTypeKind as TypeKind,
 	SlangUUID as UUID, SlangDeclKind as DeclKind,
@@ -37,14 +69,17 @@ const fn uuid(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> UUID {
 
 pub enum Error {
 	Code(sys::SlangResult),
-	Blob(Blob),
+	Diagnostics(Diagnostics),
+	/// A program was linked with an entry point its module doesn't have.
+	EntryPointNotFound(String),
 }
 
 impl std::fmt::Debug for Error {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
 			Error::Code(code) => write!(f, "{}", code),
-			Error::Blob(blob) => write!(f, "{}", blob.as_str().unwrap()),
+			Error::Diagnostics(diagnostics) => write!(f, "{}", diagnostics),
+			Error::EntryPointNotFound(name) => write!(f, "entry point `{name}` not found"),
 		}
 	}
 }
@@ -62,15 +97,72 @@ pub(crate) fn succeeded(result: sys::SlangResult) -> bool {
 	result >= 0
 }
 
-fn result_from_blob(code: sys::SlangResult, blob: *mut sys::slang_IBlob) -> Result<()> {
-	if code < 0 && !blob.is_null() {
-		Err(Error::Blob(Blob(IUnknown(
-			std::ptr::NonNull::new(blob as *mut _).unwrap(),
-		))))
-	} else if code < 0 {
-		Err(Error::Code(code))
+// Result codes, bindgen can't evaluate the `SLANG_MAKE_ERROR` macros that define them.
+// Generic errors are the Windows ones, with facility 0 or 7 (FACILITY_WIN32) for `E_INVALID_ARG`.
+// Slang's own errors use the core facility 0x200.
+const E_FAIL: sys::SlangResult = 0x8000_4005_u32 as _;
+pub(crate) const E_NOT_IMPLEMENTED: sys::SlangResult = 0x8000_4001_u32 as _;
+pub(crate) const E_NO_INTERFACE: sys::SlangResult = 0x8000_4002_u32 as _;
+pub(crate) const E_INVALID_ARG: sys::SlangResult = 0x8007_0057_u32 as _;
+pub(crate) const E_CANNOT_OPEN: sys::SlangResult = 0x8200_0004_u32 as _;
+pub(crate) const E_NOT_FOUND: sys::SlangResult = 0x8200_0005_u32 as _;
+
+fn diagnostics_from_blob(blob: *mut sys::slang_IBlob) -> Option<Diagnostics> {
+	std::ptr::NonNull::new(blob as *mut _).map(|blob| Diagnostics::new(Blob(IUnknown(blob))))
+}
+
+/// Turns the result code and diagnostics of a call into an error or the diagnostics of a success.
+fn result_from_blob(
+	code: sys::SlangResult,
+	blob: *mut sys::slang_IBlob,
+) -> Result<Option<Diagnostics>> {
+	let diagnostics = diagnostics_from_blob(blob);
+
+	if code < 0 {
+		Err(diagnostics.map_or(Error::Code(code), Error::Diagnostics))
 	} else {
-		Ok(())
+		Ok(diagnostics)
+	}
+}
+
+/// Same as [`result_from_blob`] for functions that signal failure by returning null.
+fn result_from_ptr<T>(ptr: *mut T, blob: *mut sys::slang_IBlob) -> Result<Option<Diagnostics>> {
+	result_from_blob(if ptr.is_null() { E_FAIL } else { 0 }, blob)
+}
+
+/// Wraps a module returned by one of the `ISession::loadModule*` functions.
+fn module_from_raw(
+	module: *mut sys::slang_IModule,
+	diagnostics: *mut sys::slang_IBlob,
+) -> Result<Compiled<Module>> {
+	let diagnostics = result_from_ptr(module, diagnostics)?;
+
+	// The session keeps ownership of loaded modules, so the returned pointer is not ref-counted for us.
+	let module = Module(IUnknown(std::ptr::NonNull::new(module as *mut _).unwrap()));
+	unsafe { (module.as_unknown().vtable().ISlangUnknown_addRef)(module.as_raw()) };
+
+	Ok(Compiled {
+		value: module,
+		diagnostics,
+	})
+}
+
+/// Strings with a NUL byte can't be passed to Slang.
+pub(crate) fn c_string(s: &str) -> Result<CString> {
+	CString::new(s).map_err(|_| Error::Code(E_INVALID_ARG))
+}
+
+#[cfg(unix)]
+fn path_from_bytes(bytes: &[u8]) -> Cow<'_, Path> {
+	use std::os::unix::ffi::OsStrExt;
+	Cow::Borrowed(Path::new(std::ffi::OsStr::from_bytes(bytes)))
+}
+
+#[cfg(not(unix))]
+fn path_from_bytes(bytes: &[u8]) -> Cow<'_, Path> {
+	match String::from_utf8_lossy(bytes) {
+		Cow::Borrowed(path) => Cow::Borrowed(Path::new(path)),
+		Cow::Owned(path) => Cow::Owned(path.into()),
 	}
 }
 
@@ -151,6 +243,11 @@ unsafe impl Interface for Blob {
 }
 
 impl Blob {
+	/// Creates a blob that owns `data`, for passing Rust data to Slang.
+	pub fn from_vec(data: Vec<u8>) -> Self {
+		com::VecBlob(data).into_blob()
+	}
+
 	pub fn as_slice(&self) -> &[u8] {
 		let ptr = vcall!(self, getBufferPointer());
 		let size = vcall!(self, getBufferSize());
@@ -162,6 +259,24 @@ impl Blob {
 	}
 }
 
+impl From<Vec<u8>> for Blob {
+	fn from(data: Vec<u8>) -> Self {
+		Self::from_vec(data)
+	}
+}
+
+impl From<String> for Blob {
+	fn from(text: String) -> Self {
+		Self::from_vec(text.into_bytes())
+	}
+}
+
+impl From<&str> for Blob {
+	fn from(text: &str) -> Self {
+		Self::from_vec(text.as_bytes().to_vec())
+	}
+}
+
 #[repr(transparent)]
 #[derive(Clone)]
 pub struct GlobalSession(IUnknown);
@@ -232,32 +347,261 @@ unsafe impl Interface for Session {
 }
 
 impl Session {
-	pub fn load_module(&self, name: &str) -> Result<Module> {
-		let name = CString::new(name).unwrap();
+	pub fn load_module(&self, name: &str) -> Result<Compiled<Module>> {
+		let name = c_string(name)?;
 		let mut diagnostics = null_mut();
 
 		let module = vcall!(self, loadModule(name.as_ptr(), &mut diagnostics));
+		module_from_raw(module, diagnostics)
+	}
 
-		if module.is_null() {
-			let blob = Blob(IUnknown(
-				std::ptr::NonNull::new(diagnostics as *mut _).unwrap(),
-			));
-			Err(Error::Blob(blob))
-		} else {
-			let module = Module(IUnknown(std::ptr::NonNull::new(module as *mut _).unwrap()));
-			unsafe { (module.as_unknown().vtable().ISlangUnknown_addRef)(module.as_raw()) };
-			Ok(module)
-		}
+	/// Loads a module from an in-memory source string instead of the search paths.
+	/// The `path` is used for diagnostics and to resolve relative `#include`s.
+	pub fn load_module_from_source(
+		&self,
+		name: &str,
+		path: &str,
+		source: &str,
+	) -> Result<Compiled<Module>> {
+		let name = c_string(name)?;
+		let path = c_string(path)?;
+		let source = c_string(source)?;
+		let mut diagnostics = null_mut();
+
+		let module = vcall!(
+			self,
+			loadModuleFromSourceString(
+				name.as_ptr(),
+				path.as_ptr(),
+				source.as_ptr(),
+				&mut diagnostics
+			)
+		);
+		module_from_raw(module, diagnostics)
+	}
+
+	/// Same as [`Session::load_module_from_source`] but takes the source as a [`Blob`].
+	pub fn load_module_from_source_blob(
+		&self,
+		name: &str,
+		path: &str,
+		source: &Blob,
+	) -> Result<Compiled<Module>> {
+		let name = c_string(name)?;
+		let path = c_string(path)?;
+		let mut diagnostics = null_mut();
+
+		let module = vcall!(
+			self,
+			loadModuleFromSource(
+				name.as_ptr(),
+				path.as_ptr(),
+				source.as_raw(),
+				&mut diagnostics
+			)
+		);
+		module_from_raw(module, diagnostics)
+	}
+
+	/// Loads a module previously serialized with [`Module::serialize`] or [`Module::write_to_file`].
+	pub fn load_module_from_ir_blob(
+		&self,
+		name: &str,
+		path: &str,
+		ir: &Blob,
+	) -> Result<Compiled<Module>> {
+		let name = c_string(name)?;
+		let path = c_string(path)?;
+		let mut diagnostics = null_mut();
+
+		let module = vcall!(
+			self,
+			loadModuleFromIRBlob(name.as_ptr(), path.as_ptr(), ir.as_raw(), &mut diagnostics)
+		);
+		module_from_raw(module, diagnostics)
+	}
+
+	/// Checks whether a serialized module is still up to date with the sources at `path`.
+	/// Paths with a NUL byte are never up to date.
+	pub fn is_binary_module_up_to_date(&self, path: &str, binary_module: &Blob) -> bool {
+		let Ok(path) = c_string(path) else {
+			return false;
+		};
+		vcall!(
+			self,
+			isBinaryModuleUpToDate(path.as_ptr(), binary_module.as_raw())
+		)
+	}
+
+	pub fn specialize_type(
+		&self,
+		ty: reflection::Type<'_>,
+		args: &[SpecializationArg],
+	) -> Result<Compiled<reflection::Type<'_>>> {
+		let mut diagnostics = null_mut();
+		let ptr = vcall!(
+			self,
+			specializeType(
+				ty.as_raw() as _,
+				args.as_ptr() as _,
+				args.len() as _,
+				&mut diagnostics
+			)
+		);
+
+		let diagnostics = result_from_ptr(ptr, diagnostics)?;
+
+		Ok(Compiled {
+			value: unsafe { reflection::Type::from_raw(ptr as _).unwrap() },
+			diagnostics,
+		})
+	}
+
+	/// Computes the layout of an arbitrary type for the target at `target_index`,
+	/// without it having to be a shader parameter.
+	pub fn type_layout(
+		&self,
+		ty: reflection::Type<'_>,
+		target_index: i64,
+		rules: LayoutRules,
+	) -> Result<Compiled<reflection::TypeLayout<'_>>> {
+		let mut diagnostics = null_mut();
+		let ptr = vcall!(
+			self,
+			getTypeLayout(ty.as_raw() as _, target_index, rules, &mut diagnostics)
+		);
+
+		let diagnostics = result_from_ptr(ptr, diagnostics)?;
+
+		Ok(Compiled {
+			value: unsafe { reflection::TypeLayout::from_raw(ptr as _).unwrap() },
+			diagnostics,
+		})
+	}
+
+	/// Wraps `element_type` in a container, e.g. `StructuredBuffer<T>` or `ConstantBuffer<T>`.
+	pub fn container_type(
+		&self,
+		element_type: reflection::Type<'_>,
+		container_type: ContainerType,
+	) -> Result<Compiled<reflection::Type<'_>>> {
+		let mut diagnostics = null_mut();
+		let ptr = vcall!(
+			self,
+			getContainerType(element_type.as_raw() as _, container_type, &mut diagnostics)
+		);
+
+		let diagnostics = result_from_ptr(ptr, diagnostics)?;
+
+		Ok(Compiled {
+			value: unsafe { reflection::Type::from_raw(ptr as _).unwrap() },
+			diagnostics,
+		})
+	}
+
+	pub fn dynamic_type(&self) -> reflection::Type<'_> {
+		let ptr = vcall!(self, getDynamicType());
+		unsafe { reflection::Type::from_raw(ptr as _).unwrap() }
+	}
+
+	/// Creates a component that links the conformance of `ty` to `interface` for dynamic dispatch.
+	/// When `id_override` is `None` Slang assigns the sequential ID itself.
+	pub fn create_type_conformance_component_type(
+		&self,
+		ty: reflection::Type<'_>,
+		interface: reflection::Type<'_>,
+		id_override: Option<i64>,
+	) -> Result<Compiled<TypeConformance>> {
+		let mut type_conformance = null_mut();
+		let mut diagnostics = null_mut();
+
+		let diagnostics = result_from_blob(
+			vcall!(
+				self,
+				createTypeConformanceComponentType(
+					ty.as_raw() as _,
+					interface.as_raw() as _,
+					&mut type_conformance,
+					id_override.unwrap_or(-1),
+					&mut diagnostics
+				)
+			),
+			diagnostics,
+		)?;
+
+		Ok(Compiled {
+			value: TypeConformance(IUnknown(
+				std::ptr::NonNull::new(type_conformance as *mut _).unwrap(),
+			)),
+			diagnostics,
+		})
+	}
+
+	pub fn type_rtti_mangled_name(&self, ty: reflection::Type<'_>) -> Result<Blob> {
+		let mut name = null_mut();
+		result_from_blob(
+			vcall!(self, getTypeRTTIMangledName(ty.as_raw() as _, &mut name)),
+			null_mut(),
+		)?;
+
+		Ok(Blob(IUnknown(
+			std::ptr::NonNull::new(name as *mut _).unwrap(),
+		)))
+	}
+
+	pub fn type_conformance_witness_mangled_name(
+		&self,
+		ty: reflection::Type<'_>,
+		interface: reflection::Type<'_>,
+	) -> Result<Blob> {
+		let mut name = null_mut();
+		result_from_blob(
+			vcall!(
+				self,
+				getTypeConformanceWitnessMangledName(
+					ty.as_raw() as _,
+					interface.as_raw() as _,
+					&mut name
+				)
+			),
+			null_mut(),
+		)?;
+
+		Ok(Blob(IUnknown(
+			std::ptr::NonNull::new(name as *mut _).unwrap(),
+		)))
+	}
+
+	/// Returns the ID Slang uses to identify the conformance of `ty` to `interface` at runtime.
+	pub fn type_conformance_witness_sequential_id(
+		&self,
+		ty: reflection::Type<'_>,
+		interface: reflection::Type<'_>,
+	) -> Result<u32> {
+		let mut id = 0;
+		result_from_blob(
+			vcall!(
+				self,
+				getTypeConformanceWitnessSequentialID(
+					ty.as_raw() as _,
+					interface.as_raw() as _,
+					&mut id
+				)
+			),
+			null_mut(),
+		)?;
+
+		Ok(id)
 	}
 
 	pub fn create_composite_component_type(
 		&self,
 		components: &[ComponentType],
-	) -> Result<ComponentType> {
+	) -> Result<Compiled<ComponentType>> {
 		let mut composite_component_type = null_mut();
 		let mut diagnostics = null_mut();
 
-		result_from_blob(
+		let diagnostics = result_from_blob(
 			vcall!(
 				self,
 				createCompositeComponentType(
@@ -270,9 +614,12 @@ impl Session {
 			diagnostics,
 		)?;
 
-		Ok(ComponentType(IUnknown(
-			std::ptr::NonNull::new(composite_component_type as *mut _).unwrap(),
-		)))
+		Ok(Compiled {
+			value: ComponentType(IUnknown(
+				std::ptr::NonNull::new(composite_component_type as *mut _).unwrap(),
+			)),
+			diagnostics,
+		})
 	}
 }
 
@@ -321,38 +668,125 @@ unsafe impl Interface for ComponentType {
 }
 
 impl ComponentType {
-	pub fn layout(&self, target: i64) -> Result<&reflection::Shader> {
+	pub fn layout(&self, target: i64) -> Result<Compiled<reflection::Shader<'_>>> {
 		let mut diagnostics = null_mut();
 		let ptr = vcall!(self, getLayout(target, &mut diagnostics));
 
-		if ptr.is_null() {
-			Err(Error::Blob(Blob(IUnknown(
-				std::ptr::NonNull::new(diagnostics as *mut _).unwrap(),
-			))))
-		} else {
-			Ok(unsafe { &*(ptr as *const _) })
-		}
+		let diagnostics = result_from_ptr(ptr, diagnostics)?;
+
+		Ok(Compiled {
+			value: unsafe { reflection::Shader::from_raw(ptr as _).unwrap() },
+			diagnostics,
+		})
 	}
 
-	pub fn link(&self) -> Result<ComponentType> {
-		let mut linked_component_type = null_mut();
+	pub fn specialization_param_count(&self) -> i64 {
+		vcall!(self, getSpecializationParamCount())
+	}
+
+	pub fn specialize(&self, args: &[SpecializationArg]) -> Result<Compiled<ComponentType>> {
+		let mut specialized_component_type = null_mut();
 		let mut diagnostics = null_mut();
 
-		result_from_blob(
-			vcall!(self, link(&mut linked_component_type, &mut diagnostics)),
+		let diagnostics = result_from_blob(
+			vcall!(
+				self,
+				specialize(
+					args.as_ptr() as _,
+					args.len() as _,
+					&mut specialized_component_type,
+					&mut diagnostics
+				)
+			),
+			diagnostics,
+		)?;
+
+		Ok(Compiled {
+			value: ComponentType(IUnknown(
+				std::ptr::NonNull::new(specialized_component_type as *mut _).unwrap(),
+			)),
 			diagnostics,
+		})
+	}
+
+	/// Returns a copy of this entry point component that is exported under `new_name`.
+	pub fn rename_entry_point(&self, new_name: &str) -> Result<ComponentType> {
+		let new_name = c_string(new_name)?;
+		let mut entry_point = null_mut();
+
+		result_from_blob(
+			vcall!(self, renameEntryPoint(new_name.as_ptr(), &mut entry_point)),
+			null_mut(),
 		)?;
 
 		Ok(ComponentType(IUnknown(
-			std::ptr::NonNull::new(linked_component_type as *mut _).unwrap(),
+			std::ptr::NonNull::new(entry_point as *mut _).unwrap(),
 		)))
 	}
 
-	pub fn entry_point_code(&self, index: i64, target: i64) -> Result<Blob> {
+	pub fn link(&self) -> Result<Compiled<ComponentType>> {
+		let mut linked_component_type = null_mut();
+		let mut diagnostics = null_mut();
+
+		let diagnostics = result_from_blob(
+			vcall!(self, link(&mut linked_component_type, &mut diagnostics)),
+			diagnostics,
+		)?;
+
+		Ok(Compiled {
+			value: ComponentType(IUnknown(
+				std::ptr::NonNull::new(linked_component_type as *mut _).unwrap(),
+			)),
+			diagnostics,
+		})
+	}
+
+	pub fn link_with_options(&self, options: &CompilerOptions) -> Result<Compiled<ComponentType>> {
+		let mut linked_component_type = null_mut();
+		let mut diagnostics = null_mut();
+
+		let diagnostics = result_from_blob(
+			vcall!(
+				self,
+				linkWithOptions(
+					&mut linked_component_type,
+					options.options.len() as _,
+					options.options.as_ptr() as _,
+					&mut diagnostics
+				)
+			),
+			diagnostics,
+		)?;
+
+		Ok(Compiled {
+			value: ComponentType(IUnknown(
+				std::ptr::NonNull::new(linked_component_type as *mut _).unwrap(),
+			)),
+			diagnostics,
+		})
+	}
+
+	/// Returns the code for the whole program, e.g. a single SPIR-V module containing all entry points.
+	pub fn target_code(&self, target: i64) -> Result<Compiled<Blob>> {
 		let mut code = null_mut();
 		let mut diagnostics = null_mut();
 
-		result_from_blob(
+		let diagnostics = result_from_blob(
+			vcall!(self, getTargetCode(target, &mut code, &mut diagnostics)),
+			diagnostics,
+		)?;
+
+		Ok(Compiled {
+			value: Blob(IUnknown(std::ptr::NonNull::new(code as *mut _).unwrap())),
+			diagnostics,
+		})
+	}
+
+	pub fn entry_point_code(&self, index: i64, target: i64) -> Result<Compiled<Blob>> {
+		let mut code = null_mut();
+		let mut diagnostics = null_mut();
+
+		let diagnostics = result_from_blob(
 			vcall!(
 				self,
 				getEntryPointCode(index, target, &mut code, &mut diagnostics)
@@ -360,16 +794,62 @@ impl ComponentType {
 			diagnostics,
 		)?;
 
-		Ok(Blob(IUnknown(
-			std::ptr::NonNull::new(code as *mut _).unwrap(),
+		Ok(Compiled {
+			value: Blob(IUnknown(std::ptr::NonNull::new(code as *mut _).unwrap())),
+			diagnostics,
+		})
+	}
+
+	/// Compiles an entry point to native code that can be called from Rust, for the
+	/// `ShaderHostCallable` and `HostHostCallable` targets.
+	pub fn entry_point_host_callable(
+		&self,
+		index: i64,
+		target: i64,
+	) -> Result<Compiled<SharedLibrary>> {
+		let mut shared_library = null_mut();
+		let mut diagnostics = null_mut();
+
+		let diagnostics = result_from_blob(
+			vcall!(
+				self,
+				getEntryPointHostCallable(
+					index as _,
+					target as _,
+					&mut shared_library,
+					&mut diagnostics
+				)
+			),
+			diagnostics,
+		)?;
+
+		Ok(Compiled {
+			value: SharedLibrary(IUnknown(
+				std::ptr::NonNull::new(shared_library as *mut _).unwrap(),
+			)),
+			diagnostics,
+		})
+	}
+
+	/// The files produced for an entry point, e.g. separate debug info next to the code.
+	pub fn result_file_system(&self, index: i64, target: i64) -> Result<ResultFileSystem> {
+		let mut file_system = null_mut();
+
+		result_from_blob(
+			vcall!(self, getResultAsFileSystem(index, target, &mut file_system)),
+			null_mut(),
+		)?;
+
+		Ok(ResultFileSystem(IUnknown(
+			std::ptr::NonNull::new(file_system as *mut _).unwrap(),
 		)))
 	}
 
-	pub fn target_metadata(&self, target_index: i64) -> Result<Metadata> {
+	pub fn target_metadata(&self, target_index: i64) -> Result<Compiled<Metadata>> {
 		let mut metadata = null_mut();
 		let mut diagnostics = null_mut();
 
-		result_from_blob(
+		let diagnostics = result_from_blob(
 			vcall!(
 				self,
 				getTargetMetadata(target_index, &mut metadata, &mut diagnostics)
@@ -377,20 +857,23 @@ impl ComponentType {
 			diagnostics,
 		)?;
 
-		Ok(Metadata(IUnknown(
-			std::ptr::NonNull::new(metadata as *mut _).unwrap(),
-		)))
+		Ok(Compiled {
+			value: Metadata(IUnknown(
+				std::ptr::NonNull::new(metadata as *mut _).unwrap(),
+			)),
+			diagnostics,
+		})
 	}
 
 	pub fn entry_point_metadata(
 		&self,
 		entry_point_index: i64,
 		target_index: i64,
-	) -> Result<Metadata> {
+	) -> Result<Compiled<Metadata>> {
 		let mut metadata = null_mut();
 		let mut diagnostics = null_mut();
 
-		result_from_blob(
+		let diagnostics = result_from_blob(
 			vcall!(
 				self,
 				getEntryPointMetadata(
@@ -403,9 +886,12 @@ impl ComponentType {
 			diagnostics,
 		)?;
 
-		Ok(Metadata(IUnknown(
-			std::ptr::NonNull::new(metadata as *mut _).unwrap(),
-		)))
+		Ok(Compiled {
+			value: Metadata(IUnknown(
+				std::ptr::NonNull::new(metadata as *mut _).unwrap(),
+			)),
+			diagnostics,
+		})
 	}
 }
 
@@ -430,9 +916,9 @@ unsafe impl Downcast<ComponentType> for EntryPoint {
 }
 
 impl EntryPoint {
-	pub fn function_reflection(&self) -> &reflection::Function {
+	pub fn function_reflection(&self) -> reflection::Function<'_> {
 		let ptr = vcall!(self, getFunctionReflection());
-		unsafe { &*(ptr as *const _) }
+		unsafe { reflection::Function::from_raw(ptr as _).unwrap() }
 	}
 }
 
@@ -486,6 +972,33 @@ impl Module {
 		)?)))
 	}
 
+	/// Finds an entry point by name and checks it against `stage`.
+	/// Unlike [`Module::find_entry_point_by_name`] the function doesn't need a `[shader(...)]` attribute.
+	pub fn find_and_check_entry_point(
+		&self,
+		name: &str,
+		stage: Stage,
+	) -> Result<Compiled<EntryPoint>> {
+		let name = c_string(name)?;
+		let mut entry_point = null_mut();
+		let mut diagnostics = null_mut();
+
+		let diagnostics = result_from_blob(
+			vcall!(
+				self,
+				findAndCheckEntryPoint(name.as_ptr(), stage, &mut entry_point, &mut diagnostics)
+			),
+			diagnostics,
+		)?;
+
+		Ok(Compiled {
+			value: EntryPoint(IUnknown(
+				std::ptr::NonNull::new(entry_point as *mut _).unwrap(),
+			)),
+			diagnostics,
+		})
+	}
+
 	pub fn entry_point_count(&self) -> u32 {
 		vcall!(self, getDefinedEntryPointCount()) as _
 	}
@@ -498,6 +1011,21 @@ impl Module {
 		)?)))
 	}
 
+	pub fn serialize(&self) -> Result<Blob> {
+		let mut blob = null_mut();
+		result_from_blob(vcall!(self, serialize(&mut blob)), null_mut())?;
+
+		Ok(Blob(IUnknown(
+			std::ptr::NonNull::new(blob as *mut _).unwrap(),
+		)))
+	}
+
+	pub fn write_to_file(&self, path: &str) -> Result<()> {
+		let path = c_string(path)?;
+		result_from_blob(vcall!(self, writeToFile(path.as_ptr())), null_mut())?;
+		Ok(())
+	}
+
 	pub fn name(&self) -> &str {
 		let name = vcall!(self, getName());
 		unsafe { CStr::from_ptr(name).to_str().unwrap() }
@@ -513,9 +1041,58 @@ impl Module {
 		unsafe { CStr::from_ptr(identity).to_str().unwrap() }
 	}
 
-	pub fn module_reflection(&self) -> &reflection::Decl {
+	pub fn dependency_file_count(&self) -> i32 {
+		vcall!(self, getDependencyFileCount())
+	}
+
+	/// Outside of Unix, paths that aren't valid UTF-8 are converted lossily.
+	pub fn dependency_file_path(&self, index: i32) -> Cow<'_, Path> {
+		let path = vcall!(self, getDependencyFilePath(index));
+		path_from_bytes(unsafe { CStr::from_ptr(path).to_bytes() })
+	}
+
+	/// All files this module was built from, including `import`ed modules and `#include`d files.
+	pub fn dependency_file_paths(&self) -> impl ExactSizeIterator<Item = Cow<'_, Path>> {
+		(0..self.dependency_file_count()).map(move |i| self.dependency_file_path(i))
+	}
+
+	pub fn module_reflection(&self) -> reflection::Decl<'_> {
 		let ptr = vcall!(self, getModuleReflection());
-		unsafe { &*(ptr as *const _) }
+		unsafe { reflection::Decl::from_raw(ptr as _).unwrap() }
+	}
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct SpecializationArg<'a> {
+	inner: sys::slang_SpecializationArg,
+	_phantom: PhantomData<&'a ()>,
+}
+
+impl<'a> SpecializationArg<'a> {
+	pub fn from_type(ty: reflection::Type<'a>) -> Self {
+		Self {
+			inner: sys::slang_SpecializationArg {
+				kind: sys::slang_SpecializationArg_Kind::Type,
+				__bindgen_anon_1: sys::slang_SpecializationArg__bindgen_ty_1 {
+					type_: ty.as_raw() as _,
+				},
+			},
+			_phantom: PhantomData,
+		}
+	}
+
+	/// Specializes with a Slang expression, e.g. the name of a type or a constant value.
+	pub fn from_expr(expr: &'a CStr) -> Self {
+		Self {
+			inner: sys::slang_SpecializationArg {
+				kind: sys::slang_SpecializationArg_Kind::Expr,
+				__bindgen_anon_1: sys::slang_SpecializationArg__bindgen_ty_1 {
+					expr: expr.as_ptr(),
+				},
+			},
+			_phantom: PhantomData,
+		}
 	}
 }
 
@@ -607,6 +1184,12 @@ impl<'a> SessionDesc<'a> {
 		self.inner.compilerOptionEntryCount = options.options.len() as _;
 		self
 	}
+
+	/// Loads files through `file_system` instead of the operating system.
+	pub fn file_system(mut self, file_system: &'a CustomFileSystem) -> Self {
+		self.inner.fileSystem = file_system.as_ptr();
+		self
+	}
 }
 
 macro_rules! option {
@@ -0,0 +1,400 @@
+use std::fmt;
+
+use super::{Shader, TypeLayout};
+use crate::{
+	BindingType, ImageFormat, ParameterCategory, ResourceAccess, ResourceShape, ScalarType, Stage,
+	TypeKind,
+};
+
+const STAGES: [Stage; 14] = [
+	Stage::Vertex,
+	Stage::Hull,
+	Stage::Domain,
+	Stage::Geometry,
+	Stage::Fragment,
+	Stage::Compute,
+	Stage::RayGeneration,
+	Stage::Intersection,
+	Stage::AnyHit,
+	Stage::ClosestHit,
+	Stage::Miss,
+	Stage::Callable,
+	Stage::Mesh,
+	Stage::Amplification,
+];
+
+/// A set of shader stages.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StageFlags(u32);
+
+impl StageFlags {
+	pub fn contains(self, stage: Stage) -> bool {
+		self.0 & Self::from(stage).0 != 0
+	}
+
+	pub fn insert(&mut self, stage: Stage) {
+		self.0 |= Self::from(stage).0;
+	}
+
+	pub fn is_empty(self) -> bool {
+		self.0 == 0
+	}
+
+	pub fn iter(self) -> impl Iterator<Item = Stage> {
+		STAGES
+			.into_iter()
+			.filter(move |&stage| self.contains(stage))
+	}
+}
+
+impl From<Stage> for StageFlags {
+	fn from(stage: Stage) -> Self {
+		Self(1 << stage as u32)
+	}
+}
+
+impl std::ops::BitOr for StageFlags {
+	type Output = Self;
+
+	fn bitor(self, other: Self) -> Self {
+		Self(self.0 | other.0)
+	}
+}
+
+/// The descriptor sets and push constants a program needs, e.g. to create a Vulkan pipeline layout.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DescriptorLayout {
+	/// Sorted by space.
+	pub sets: Vec<DescriptorSetLayout>,
+	/// Every stage is in at most one range, as Vulkan requires.
+	pub push_constant_ranges: Vec<PushConstantRange>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DescriptorSetLayout {
+	/// The Vulkan descriptor set index or D3D register space.
+	pub space: i64,
+	/// Sorted by binding.
+	pub bindings: Vec<DescriptorBinding>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DescriptorBinding {
+	pub binding: i64,
+	pub binding_type: BindingType,
+	/// `-1` for unsized arrays.
+	pub count: i64,
+	pub stages: StageFlags,
+	/// The format of storage images, if the shader declares one.
+	pub image_format: ImageFormat,
+	pub resource_shape: ResourceShape,
+	pub resource_access: ResourceAccess,
+	/// The scalar type textures return, e.g. `Float32` for `Texture2D<float4>`.
+	pub result_type: ScalarType,
+	/// The size of the contents of constant buffers, or of one element of structured buffers.
+	pub uniform_size: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PushConstantRange {
+	pub offset: usize,
+	pub size: usize,
+	pub stages: StageFlags,
+}
+
+/// Parameters of different types or array sizes at the same binding, e.g. from explicit bindings
+/// that overlap.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BindingConflict {
+	pub space: i64,
+	pub binding: i64,
+	/// The binding type and count of the first parameter, then of the one that conflicts with it.
+	pub first: (BindingType, i64),
+	pub second: (BindingType, i64),
+}
+
+impl fmt::Display for BindingConflict {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let Self {
+			space,
+			binding,
+			first,
+			second,
+		} = self;
+
+		write!(
+			f,
+			"binding {binding} in space {space} is used for {} {:?} and {} {:?}",
+			first.1, first.0, second.1, second.0
+		)
+	}
+}
+
+impl std::error::Error for BindingConflict {}
+
+impl DescriptorLayout {
+	/// Global parameters are visible to every stage of the program, entry point parameters only
+	/// to their own stage. Every `ParameterBlock` gets a set of its own.
+	pub fn from_program(shader: Shader<'_>) -> Result<Self, BindingConflict> {
+		let mut layout = Self::default();
+
+		let all_stages = shader
+			.entry_points()
+			.fold(StageFlags::default(), |stages, entry_point| {
+				stages | entry_point.stage().into()
+			});
+
+		layout.add_block(shader.global_params_type_layout(), 0, all_stages)?;
+
+		for entry_point in shader.entry_points() {
+			layout.add_block(entry_point.type_layout(), 0, entry_point.stage().into())?;
+		}
+
+		layout.sets.sort_by_key(|set| set.space);
+		for set in &mut layout.sets {
+			set.bindings.sort_by_key(|binding| binding.binding);
+		}
+
+		Ok(layout)
+	}
+
+	/// Adds a scope that has its own space: the global scope, an entry point or a parameter block.
+	fn add_block(
+		&mut self,
+		layout: TypeLayout<'_>,
+		space: i64,
+		stages: StageFlags,
+	) -> Result<(), BindingConflict> {
+		// Uniforms in the scope are put in a buffer Slang adds, which isn't one of the ranges.
+		let (element, container) = match (layout.kind(), layout.element_type_layout()) {
+			(TypeKind::ConstantBuffer | TypeKind::ParameterBlock, Some(element)) => {
+				(element, layout.container_var_layout())
+			}
+			_ => (layout, None),
+		};
+
+		let uniform_size = element.size(ParameterCategory::Uniform);
+
+		if let (Some(container), true) = (container, uniform_size != 0) {
+			let categories = container.type_layout().categories().collect::<Vec<_>>();
+
+			if categories.contains(&ParameterCategory::PushConstantBuffer) {
+				self.add_push_constants(uniform_size, stages);
+			} else if categories.contains(&ParameterCategory::DescriptorTableSlot) {
+				let binding = DescriptorBinding {
+					uniform_size,
+					..DescriptorBinding::new(
+						container.offset(ParameterCategory::DescriptorTableSlot) as i64,
+						BindingType::ConstantBuffer,
+						1,
+						stages,
+					)
+				};
+
+				let space = space
+					+ container.binding_space_with_category(ParameterCategory::DescriptorTableSlot)
+						as i64;
+				self.add_binding(space, binding)?;
+			}
+		}
+
+		self.add_descriptor_ranges(element, space, stages)?;
+		self.add_sub_objects(element, space, stages)
+	}
+
+	fn add_descriptor_ranges(
+		&mut self,
+		layout: TypeLayout<'_>,
+		space: i64,
+		stages: StageFlags,
+	) -> Result<(), BindingConflict> {
+		for set in 0..layout.descriptor_set_count() {
+			let set_space = space + layout.descriptor_set_space_offset(set);
+
+			for range in 0..layout.descriptor_set_descriptor_range_count(set) {
+				let binding_type = layout.descriptor_set_descriptor_range_type(set, range);
+				let category = layout.descriptor_set_descriptor_range_category(set, range);
+
+				// Push constants are ranges of their own, nested parameter blocks are sub-objects.
+				if binding_type == BindingType::PushConstant
+					|| category == ParameterCategory::SubElementRegisterSpace
+				{
+					continue;
+				}
+
+				let mut binding = DescriptorBinding::new(
+					layout.descriptor_set_descriptor_range_index_offset(set, range),
+					binding_type,
+					layout.descriptor_set_descriptor_range_descriptor_count(set, range),
+					stages,
+				);
+
+				if let Some(binding_range) = binding_range(layout, set, range) {
+					binding.describe_resource(layout, binding_range);
+				}
+
+				self.add_binding(set_space, binding)?;
+			}
+		}
+
+		Ok(())
+	}
+
+	fn add_sub_objects(
+		&mut self,
+		layout: TypeLayout<'_>,
+		space: i64,
+		stages: StageFlags,
+	) -> Result<(), BindingConflict> {
+		for sub_object in 0..layout.sub_object_range_count() {
+			let binding_range = layout.sub_object_range_binding_range_index(sub_object);
+
+			match layout.binding_range_type(binding_range) {
+				BindingType::ParameterBlock => {
+					let block_space = space + layout.sub_object_range_space_offset(sub_object);
+					let block = layout.binding_range_leaf_type_layout(binding_range);
+					self.add_block(block, block_space, stages)?;
+				}
+				BindingType::PushConstant => {
+					let buffer = layout.binding_range_leaf_type_layout(binding_range);
+					let size = buffer
+						.element_type_layout()
+						.map_or(0, |element| element.size(ParameterCategory::Uniform));
+
+					if size != 0 {
+						self.add_push_constants(size, stages);
+					}
+				}
+				_ => {}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Bindings shared by several entry points are merged, their type and count have to match.
+	fn add_binding(
+		&mut self,
+		space: i64,
+		binding: DescriptorBinding,
+	) -> Result<(), BindingConflict> {
+		let set = match self.sets.iter().position(|set| set.space == space) {
+			Some(index) => &mut self.sets[index],
+			None => {
+				self.sets.push(DescriptorSetLayout {
+					space,
+					bindings: Vec::new(),
+				});
+				self.sets.last_mut().unwrap()
+			}
+		};
+
+		match set
+			.bindings
+			.iter_mut()
+			.find(|existing| existing.binding == binding.binding)
+		{
+			Some(existing)
+				if (existing.binding_type, existing.count)
+					!= (binding.binding_type, binding.count) =>
+			{
+				return Err(BindingConflict {
+					space,
+					binding: binding.binding,
+					first: (existing.binding_type, existing.count),
+					second: (binding.binding_type, binding.count),
+				});
+			}
+			Some(existing) => existing.stages = existing.stages | binding.stages,
+			None => set.bindings.push(binding),
+		}
+
+		Ok(())
+	}
+
+	/// Slang puts the global push constants and those of every entry point at offset 0. A stage can
+	/// only be in one range, so it gets the largest size it needs and stages with the same size
+	/// share a range.
+	fn add_push_constants(&mut self, size: usize, stages: StageFlags) {
+		let mut sizes: Vec<(Stage, usize)> = Vec::new();
+
+		let ranges = self
+			.push_constant_ranges
+			.iter()
+			.map(|range| (range.size, range.stages));
+
+		for (size, stages) in ranges.chain([(size, stages)]) {
+			for stage in stages.iter() {
+				match sizes.iter_mut().find(|(existing, _)| *existing == stage) {
+					Some((_, existing)) => *existing = (*existing).max(size),
+					None => sizes.push((stage, size)),
+				}
+			}
+		}
+
+		self.push_constant_ranges.clear();
+
+		for (stage, size) in sizes {
+			match self
+				.push_constant_ranges
+				.iter_mut()
+				.find(|range| range.size == size)
+			{
+				Some(range) => range.stages.insert(stage),
+				None => self.push_constant_ranges.push(PushConstantRange {
+					offset: 0,
+					size,
+					stages: stage.into(),
+				}),
+			}
+		}
+	}
+}
+
+impl DescriptorBinding {
+	fn new(binding: i64, binding_type: BindingType, count: i64, stages: StageFlags) -> Self {
+		Self {
+			binding,
+			binding_type,
+			count,
+			stages,
+			image_format: ImageFormat::SLANGIMAGEFORMATUnknown,
+			resource_shape: ResourceShape::SlangResourceNone,
+			resource_access: ResourceAccess::None,
+			result_type: ScalarType::None,
+			uniform_size: 0,
+		}
+	}
+
+	/// Descriptor ranges don't say what they hold, it's taken from the binding range they belong to.
+	fn describe_resource(&mut self, layout: TypeLayout<'_>, binding_range: i64) {
+		self.image_format = layout.binding_range_image_format(binding_range);
+
+		// Arrays are already unwrapped.
+		let leaf = layout.binding_range_leaf_type_layout(binding_range);
+
+		if leaf.kind() == TypeKind::Resource {
+			self.resource_shape = leaf.resource_shape();
+			self.resource_access = leaf.resource_access();
+
+			if let Some(result_type) = leaf.resource_result_type() {
+				self.result_type = result_type.scalar_type();
+			}
+		}
+
+		// Only buffers have an element type.
+		if let Some(element) = leaf.element_type_layout() {
+			self.uniform_size = element.size(ParameterCategory::Uniform);
+		}
+	}
+}
+
+fn binding_range(layout: TypeLayout<'_>, set: i64, range: i64) -> Option<i64> {
+	(0..layout.binding_range_count()).find(|&binding_range| {
+		let first = layout.binding_range_first_descriptor_range_index(binding_range);
+		let count = layout.binding_range_descriptor_range_count(binding_range);
+
+		layout.binding_range_descriptor_set_index(binding_range) == set
+			&& (first..first + count).contains(&range)
+	})
+}
@@ -1,43 +1,43 @@
-use super::{rcall, Type, UserAttribute, Variable};
+use super::{handle, rcall, Type, UserAttribute, Variable};
 use slang_sys as sys;
 
-#[repr(transparent)]
-pub struct Function(sys::SlangReflectionFunction);
+handle!(Function, sys::SlangReflectionFunction);
 
-impl Function {
-	pub fn name(&self) -> &str {
+impl<'prog> Function<'prog> {
+	pub fn name(self) -> &'prog str {
 		let name = rcall!(spReflectionFunction_GetName(self));
 		unsafe { std::ffi::CStr::from_ptr(name).to_str().unwrap() }
 	}
 
-	pub fn return_type(&self) -> &Type {
-		rcall!(spReflectionFunction_GetResultType(self) as &Type)
+	/// `None` for functions whose result type couldn't be resolved, e.g. in generics.
+	pub fn return_type(self) -> Option<Type<'prog>> {
+		rcall!(spReflectionFunction_GetResultType(self) as Option<Type>)
 	}
 
-	pub fn parameter_count(&self) -> u32 {
+	pub fn parameter_count(self) -> u32 {
 		rcall!(spReflectionFunction_GetParameterCount(self))
 	}
 
-	pub fn parameter_by_index(&self, index: u32) -> Option<&Variable> {
-		rcall!(spReflectionFunction_GetParameter(self, index) as Option<&Variable>)
+	pub fn parameter_by_index(self, index: u32) -> Option<Variable<'prog>> {
+		rcall!(spReflectionFunction_GetParameter(self, index) as Option<Variable>)
 	}
 
-	pub fn parameters(&self) -> impl ExactSizeIterator<Item = &Variable> {
+	pub fn parameters(self) -> impl ExactSizeIterator<Item = Variable<'prog>> {
 		(0..self.parameter_count())
-			.map(move |i| rcall!(spReflectionFunction_GetParameter(self, i) as &Variable))
+			.map(move |i| rcall!(spReflectionFunction_GetParameter(self, i) as Variable))
 	}
 
-	pub fn user_attribute_count(&self) -> u32 {
+	pub fn user_attribute_count(self) -> u32 {
 		rcall!(spReflectionFunction_GetUserAttributeCount(self))
 	}
 
-	pub fn user_attribute_by_index(&self, index: u32) -> Option<&UserAttribute> {
-		rcall!(spReflectionFunction_GetUserAttribute(self, index) as Option<&UserAttribute>)
+	pub fn user_attribute_by_index(self, index: u32) -> Option<UserAttribute<'prog>> {
+		rcall!(spReflectionFunction_GetUserAttribute(self, index) as Option<UserAttribute>)
 	}
 
-	pub fn user_attributes(&self) -> impl ExactSizeIterator<Item = &UserAttribute> {
+	pub fn user_attributes(self) -> impl ExactSizeIterator<Item = UserAttribute<'prog>> {
 		(0..self.user_attribute_count())
-			.map(move |i| rcall!(spReflectionFunction_GetUserAttribute(self, i) as &UserAttribute))
+			.map(move |i| rcall!(spReflectionFunction_GetUserAttribute(self, i) as UserAttribute))
 	}
 
 	// TODO: find_user_attribute_by_name
@@ -46,20 +46,20 @@ impl Function {
 	// TODO: apply_specializations
 	// TODO: specialize_with_arg_types
 
-	pub fn is_overloaded(&self) -> bool {
+	pub fn is_overloaded(self) -> bool {
 		rcall!(spReflectionFunction_isOverloaded(self))
 	}
 
-	pub fn overload_count(&self) -> u32 {
+	pub fn overload_count(self) -> u32 {
 		rcall!(spReflectionFunction_getOverloadCount(self))
 	}
 
-	pub fn overload_by_index(&self, index: u32) -> Option<&Function> {
-		rcall!(spReflectionFunction_getOverload(self, index) as Option<&Function>)
+	pub fn overload_by_index(self, index: u32) -> Option<Function<'prog>> {
+		rcall!(spReflectionFunction_getOverload(self, index) as Option<Function>)
 	}
 
-	pub fn overloads(&self) -> impl ExactSizeIterator<Item = &Function> {
+	pub fn overloads(self) -> impl ExactSizeIterator<Item = Function<'prog>> {
 		(0..self.overload_count())
-			.map(move |i| rcall!(spReflectionFunction_getOverload(self, i) as &Function))
+			.map(move |i| rcall!(spReflectionFunction_getOverload(self, i) as Function))
 	}
 }
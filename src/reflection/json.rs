@@ -0,0 +1,829 @@
+use std::fmt::Write;
+
+use super::{
+	EntryPoint, Shader, Type, TypeLayout, TypeParameter, UserAttribute, Variable, VariableLayout,
+};
+use crate::{ParameterCategory, ResourceAccess, ScalarType, Stage, TypeKind};
+
+// Bits of `SlangResourceShape`, bindgen only generates the combinations slang.h names.
+const RESOURCE_BASE_SHAPE_MASK: u32 = 0x0f;
+const TEXTURE_FEEDBACK_FLAG: u32 = 0x10;
+const TEXTURE_ARRAY_FLAG: u32 = 0x40;
+const TEXTURE_MULTISAMPLE_FLAG: u32 = 0x80;
+
+impl Shader<'_> {
+	/// Writes the reflection data in the format of `slangc -reflection-json`.
+	///
+	/// The per entry point `bindings` that slangc adds after generating code aren't included.
+	pub fn to_json(self) -> String {
+		let mut writer = JsonWriter::default();
+		writer.program(self);
+		writer.out
+	}
+}
+
+/// Follows the output of the `PrettyWriter` slangc uses, including where it puts line breaks.
+#[derive(Default)]
+struct JsonWriter {
+	out: String,
+	indent: usize,
+	at_line_start: bool,
+	/// Whether the innermost object already has a member and needs a comma before the next one.
+	need_comma: Vec<bool>,
+}
+
+impl JsonWriter {
+	fn write(&mut self, text: &str) {
+		for c in text.chars() {
+			if self.at_line_start && c != '\n' {
+				self.out.push_str(&"    ".repeat(self.indent));
+				self.at_line_start = false;
+			}
+
+			self.out.push(c);
+			self.at_line_start = c == '\n';
+		}
+	}
+
+	fn write_display(&mut self, value: impl std::fmt::Display) {
+		self.write(&value.to_string());
+	}
+
+	fn write_string(&mut self, text: &str) {
+		let mut escaped = String::with_capacity(text.len() + 2);
+		escaped.push('"');
+
+		for c in text.chars() {
+			match c {
+				'"' => escaped.push_str("\\\""),
+				'\\' => escaped.push_str("\\\\"),
+				'\n' => escaped.push_str("\\n"),
+				'\r' => escaped.push_str("\\r"),
+				'\t' => escaped.push_str("\\t"),
+				c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+				c => escaped.push(c),
+			}
+		}
+
+		escaped.push('"');
+		self.write(&escaped);
+	}
+
+	fn maybe_comma(&mut self) {
+		if let Some(need_comma) = self.need_comma.last_mut() {
+			if !*need_comma {
+				*need_comma = true;
+				return;
+			}
+		}
+
+		self.write(",\n");
+	}
+
+	/// Runs `f` with its own comma state, like an object nested in the current one.
+	fn tracked(&mut self, f: impl FnOnce(&mut Self)) {
+		self.need_comma.push(false);
+		f(self);
+		self.need_comma.pop();
+	}
+
+	fn program(&mut self, shader: Shader<'_>) {
+		self.write("{\n");
+		self.indent += 1;
+		self.write("\"parameters\": [\n");
+		self.indent += 1;
+
+		for (i, parameter) in shader.parameters().enumerate() {
+			if i != 0 {
+				self.write(",\n");
+			}
+			self.param(parameter);
+		}
+
+		self.indent -= 1;
+		self.write("\n]");
+
+		if shader.entry_point_count() != 0 {
+			self.write(",\n\"entryPoints\": [\n");
+			self.indent += 1;
+
+			for (i, entry_point) in shader.entry_points().enumerate() {
+				if i != 0 {
+					self.write(",\n");
+				}
+				self.entry_point(entry_point);
+			}
+
+			self.indent -= 1;
+			self.write("\n]");
+		}
+
+		if shader.type_parameter_count() != 0 {
+			self.write(",\n\"typeParams\":\n[\n");
+			self.indent += 1;
+
+			for (i, type_parameter) in shader.type_parameters().enumerate() {
+				if i != 0 {
+					self.write(",\n");
+				}
+				self.type_parameter(type_parameter);
+			}
+
+			self.indent -= 1;
+			self.write("\n]");
+		}
+
+		let hashed_string_count = shader.hashed_string_count();
+
+		if hashed_string_count != 0 {
+			self.write(",\n\"hashedStrings\": {\n");
+			self.indent += 1;
+
+			for i in 0..hashed_string_count {
+				let string = shader.hashed_string(i).unwrap_or_default();
+				self.write_string(string);
+				self.write(": ");
+				self.write_display(super::compute_string_hash(string) as i32);
+
+				if i + 1 < hashed_string_count {
+					self.write(",");
+				}
+				self.write("\n");
+			}
+
+			self.indent -= 1;
+			self.write("}");
+		}
+
+		self.indent -= 1;
+		self.write("\n}\n");
+	}
+
+	fn entry_point(&mut self, entry_point: EntryPoint<'_>) {
+		self.write("{\n");
+		self.indent += 1;
+
+		self.name(entry_point.name());
+
+		if let Some(stage) = stage_name(entry_point.stage()) {
+			self.write(",\n\"stage\": ");
+			self.write_string(stage);
+		}
+
+		if entry_point.parameter_count() != 0 {
+			self.write(",\n\"parameters\": [\n");
+			self.indent += 1;
+
+			for (i, parameter) in entry_point.parameters().enumerate() {
+				if i != 0 {
+					self.write(",\n");
+				}
+				self.param(parameter);
+			}
+
+			self.indent -= 1;
+			self.write("\n]");
+		}
+
+		if entry_point.uses_any_sample_rate_input() {
+			self.write(",\n\"usesAnySampleRateInput\": true");
+		}
+
+		if let Some(result) = entry_point.result_var_layout() {
+			self.write(",\n\"result\": ");
+			self.param(result);
+		}
+
+		if entry_point.stage() == Stage::Compute {
+			let [x, y, z] = entry_point.compute_thread_group_size();
+			self.write(&format!(",\n\"threadGroupSize\": [{x}, {y}, {z}]"));
+		}
+
+		self.indent -= 1;
+		self.write("\n}");
+	}
+
+	/// Top level and entry point parameters, which put the binding before the type.
+	fn param(&mut self, parameter: VariableLayout<'_>) {
+		self.write("{\n");
+		self.indent += 1;
+
+		self.tracked(|writer| {
+			if let Some(name) = parameter.variable().and_then(Variable::name) {
+				writer.maybe_comma();
+				writer.name(name);
+			}
+
+			writer.var_binding(parameter);
+
+			writer.maybe_comma();
+			writer.write("\"type\": ");
+			writer.type_layout(parameter.type_layout());
+		});
+
+		self.indent -= 1;
+		self.write("\n}");
+	}
+
+	fn var_layout(&mut self, layout: VariableLayout<'_>) {
+		self.write("{\n");
+		self.indent += 1;
+
+		self.tracked(|writer| {
+			let variable = layout.variable();
+
+			if let Some(name) = variable.and_then(Variable::name) {
+				writer.maybe_comma();
+				writer.name(name);
+			}
+
+			writer.maybe_comma();
+			writer.write("\"type\": ");
+			writer.type_layout(layout.type_layout());
+
+			writer.var_binding(layout);
+
+			if let Some(variable) = variable {
+				writer.user_attributes(variable.user_attributes());
+			}
+		});
+
+		self.indent -= 1;
+		self.write("\n}");
+	}
+
+	fn var_binding(&mut self, layout: VariableLayout<'_>) {
+		if let Some(stage) = stage_name(layout.stage()) {
+			self.maybe_comma();
+			self.write("\"stage\": ");
+			self.write_string(stage);
+		}
+
+		let type_layout = layout.type_layout();
+		let category_count = layout.category_count();
+
+		if category_count != 0 {
+			self.maybe_comma();
+			self.write(if category_count == 1 {
+				"\"binding\": "
+			} else {
+				"\"bindings\": [\n"
+			});
+			self.indent += 1;
+
+			for (i, category) in type_layout.categories().enumerate() {
+				if i != 0 {
+					self.write(",\n");
+				}
+
+				let element_stride = match type_layout.kind() {
+					TypeKind::Array => type_layout.element_stride(category),
+					_ => 0,
+				};
+
+				self.write("{");
+				self.binding(
+					category,
+					layout.offset(category),
+					type_layout.size(category),
+					layout.binding_space_with_category(category),
+					element_stride,
+				);
+				self.write("}");
+			}
+
+			self.indent -= 1;
+			if category_count != 1 {
+				self.write("\n]");
+			}
+		}
+
+		if let Some(semantic_name) = layout.semantic_name() {
+			self.maybe_comma();
+			self.write("\"semanticName\": ");
+			self.write_string(semantic_name);
+
+			let semantic_index = layout.semantic_index();
+			if semantic_index != 0 {
+				self.maybe_comma();
+				self.write_display(format_args!("\"semanticIndex\": {semantic_index}"));
+			}
+		}
+	}
+
+	fn binding(
+		&mut self,
+		category: ParameterCategory,
+		index: usize,
+		count: usize,
+		space: usize,
+		element_stride: usize,
+	) {
+		if category == ParameterCategory::Uniform {
+			self.write_display(format_args!(
+				"\"kind\": \"uniform\", \"offset\": {index}, \"size\": {count}"
+			));
+
+			if element_stride != 0 {
+				self.write_display(format_args!(", \"elementStride\": {element_stride}"));
+			}
+
+			return;
+		}
+
+		self.write("\"kind\": ");
+		self.write_string(category_name(category));
+
+		if space != 0 && category != ParameterCategory::RegisterSpace {
+			self.write_display(format_args!(", \"space\": {space}"));
+		}
+
+		self.write_display(format_args!(", \"index\": {index}"));
+
+		if count == usize::MAX {
+			self.write(", \"count\": \"unbounded\"");
+		} else if count != 1 {
+			self.write_display(format_args!(", \"count\": {count}"));
+		}
+	}
+
+	fn type_layout(&mut self, layout: TypeLayout<'_>) {
+		self.tracked(|writer| {
+			writer.write("{\n");
+			writer.indent += 1;
+			writer.type_layout_info(layout);
+			writer.indent -= 1;
+			writer.write("\n}");
+		});
+	}
+
+	fn type_layout_info(&mut self, layout: TypeLayout<'_>) {
+		match layout.kind() {
+			TypeKind::Array => {
+				self.maybe_comma();
+				self.write("\"kind\": \"array\"");
+
+				self.maybe_comma();
+				self.write_display(format_args!(
+					"\"elementCount\": {}",
+					layout.element_count() as i32
+				));
+
+				if let Some(element) = layout.element_type_layout() {
+					self.maybe_comma();
+					self.write("\"elementType\": ");
+					self.type_layout(element);
+				}
+
+				if layout.size(ParameterCategory::Uniform) != 0 {
+					self.maybe_comma();
+					self.write_display(format_args!(
+						"\"uniformStride\": {}",
+						layout.element_stride(ParameterCategory::Uniform) as i32
+					));
+				}
+			}
+			TypeKind::Struct => {
+				self.maybe_comma();
+				self.write("\"kind\": \"struct\"");
+
+				if let Some(name) = layout.name() {
+					self.maybe_comma();
+					self.name(name);
+				}
+
+				self.maybe_comma();
+				self.write("\"fields\": [\n");
+				self.indent += 1;
+
+				for (i, field) in layout.fields().enumerate() {
+					if i != 0 {
+						self.write(",\n");
+					}
+					self.var_layout(field);
+				}
+
+				self.indent -= 1;
+				self.write("\n]");
+
+				self.user_attributes(layout.ty().user_attributes());
+			}
+			TypeKind::ConstantBuffer => self.parameter_group(layout, "constantBuffer"),
+			TypeKind::ParameterBlock => self.parameter_group(layout, "parameterBlock"),
+			TypeKind::TextureBuffer => self.parameter_group(layout, "textureBuffer"),
+			TypeKind::ShaderStorageBuffer => self.parameter_group(layout, "shaderStorageBuffer"),
+			TypeKind::Resource if is_structured_buffer(layout.ty()) => {
+				self.resource_base(layout.ty());
+
+				if let Some(result) = layout.element_type_layout() {
+					self.maybe_comma();
+					self.write("\"resultType\": ");
+					self.type_layout(result);
+				}
+			}
+			_ => self.type_info(layout.ty()),
+		}
+	}
+
+	fn parameter_group(&mut self, layout: TypeLayout<'_>, kind: &str) {
+		self.maybe_comma();
+		self.write("\"kind\": ");
+		self.write_string(kind);
+
+		if let Some(element) = layout.element_type_layout() {
+			self.maybe_comma();
+			self.write("\"elementType\": ");
+			self.type_layout(element);
+		}
+
+		if let Some(container) = layout.container_var_layout() {
+			self.maybe_comma();
+			self.write("\"containerVarLayout\": {\n");
+			self.indent += 1;
+			self.tracked(|writer| writer.var_binding(container));
+			self.indent -= 1;
+			self.write("\n}");
+		}
+
+		if let Some(element) = layout.element_var_layout() {
+			self.maybe_comma();
+			self.write("\"elementVarLayout\": ");
+			self.var_layout(element);
+		}
+	}
+
+	fn ty(&mut self, ty: Type<'_>) {
+		self.tracked(|writer| {
+			writer.write("{\n");
+			writer.indent += 1;
+			writer.type_info(ty);
+			writer.indent -= 1;
+			writer.write("\n}");
+		});
+	}
+
+	fn type_info(&mut self, ty: Type<'_>) {
+		match ty.kind() {
+			TypeKind::SamplerState => {
+				self.maybe_comma();
+				self.write("\"kind\": \"samplerState\"");
+			}
+			TypeKind::Resource => {
+				self.resource_base(ty);
+
+				if let Some(result) = ty
+					.resource_result_type()
+					.filter(|_| is_structured_buffer(ty))
+				{
+					self.maybe_comma();
+					self.write("\"resultType\": ");
+					self.ty(result);
+				}
+			}
+			kind @ (TypeKind::ConstantBuffer
+			| TypeKind::ParameterBlock
+			| TypeKind::TextureBuffer
+			| TypeKind::ShaderStorageBuffer) => {
+				let name = match kind {
+					TypeKind::ConstantBuffer => "constantBuffer",
+					TypeKind::ParameterBlock => "parameterBlock",
+					TypeKind::TextureBuffer => "textureBuffer",
+					_ => "shaderStorageBuffer",
+				};
+
+				self.maybe_comma();
+				self.write("\"kind\": ");
+				self.write_string(name);
+
+				if let Some(element) = ty.element_type() {
+					self.maybe_comma();
+					self.write("\"elementType\": ");
+					self.ty(element);
+				}
+			}
+			TypeKind::Scalar => {
+				self.maybe_comma();
+				self.write("\"kind\": \"scalar\"");
+
+				self.maybe_comma();
+				self.write("\"scalarType\": ");
+				self.write_string(scalar_type_name(ty.scalar_type()));
+			}
+			TypeKind::Vector => {
+				self.maybe_comma();
+				self.write("\"kind\": \"vector\"");
+
+				self.maybe_comma();
+				self.write_display(format_args!(
+					"\"elementCount\": {}",
+					ty.element_count() as i32
+				));
+
+				if let Some(element) = ty.element_type() {
+					self.maybe_comma();
+					self.write("\"elementType\": ");
+					self.ty(element);
+				}
+			}
+			TypeKind::Matrix => {
+				self.maybe_comma();
+				self.write("\"kind\": \"matrix\"");
+
+				self.maybe_comma();
+				self.write_display(format_args!("\"rowCount\": {}", ty.row_count()));
+
+				self.maybe_comma();
+				self.write_display(format_args!("\"columnCount\": {}", ty.column_count()));
+
+				if let Some(element) = ty.element_type() {
+					self.maybe_comma();
+					self.write("\"elementType\": ");
+					self.ty(element);
+				}
+			}
+			TypeKind::Array => {
+				self.maybe_comma();
+				self.write("\"kind\": \"array\"");
+
+				self.maybe_comma();
+				self.write_display(format_args!(
+					"\"elementCount\": {}",
+					ty.element_count() as i32
+				));
+
+				if let Some(element) = ty.element_type() {
+					self.maybe_comma();
+					self.write("\"elementType\": ");
+					self.ty(element);
+				}
+			}
+			TypeKind::Pointer => {
+				self.maybe_comma();
+				self.write("\"kind\": \"pointer\"");
+
+				if let Some(element) = ty.element_type() {
+					self.maybe_comma();
+					self.write("\"targetType\": ");
+					self.ty(element);
+				}
+			}
+			TypeKind::Struct => {
+				self.maybe_comma();
+				self.write("\"kind\": \"struct\"");
+
+				self.maybe_comma();
+				self.name(ty.name().unwrap_or_default());
+
+				self.maybe_comma();
+				self.write("\"fields\": [\n");
+				self.indent += 1;
+
+				for (i, field) in ty.fields().enumerate() {
+					if i != 0 {
+						self.write(",\n");
+					}
+
+					self.write("{\n");
+					self.indent += 1;
+					self.name(field.name().unwrap_or_default());
+					if let Some(ty) = field.ty() {
+						self.write(",\n\"type\": ");
+						self.ty(ty);
+					}
+					self.indent -= 1;
+					self.write("\n}");
+				}
+
+				self.indent -= 1;
+				self.write("\n]");
+			}
+			TypeKind::GenericTypeParameter => {
+				self.maybe_comma();
+				self.write("\"kind\": \"GenericTypeParameter\"");
+
+				self.maybe_comma();
+				self.name(ty.name().unwrap_or_default());
+			}
+			TypeKind::Interface => {
+				self.maybe_comma();
+				self.write("\"kind\": \"Interface\"");
+
+				self.maybe_comma();
+				self.name(ty.name().unwrap_or_default());
+			}
+			TypeKind::Feedback => {
+				self.maybe_comma();
+				self.write("\"kind\": \"feedback\"");
+			}
+			TypeKind::DynamicResource => {
+				self.maybe_comma();
+				self.write("\"kind\": \"DynamicResource\"");
+			}
+			_ => {}
+		}
+
+		self.user_attributes(ty.user_attributes());
+	}
+
+	fn resource_base(&mut self, ty: Type<'_>) {
+		let shape = ty.resource_shape() as u32;
+		let access = ty.resource_access();
+
+		self.maybe_comma();
+		self.write("\"kind\": \"resource\"");
+
+		self.maybe_comma();
+		self.write("\"baseShape\": ");
+		self.write_string(base_shape_name(shape & RESOURCE_BASE_SHAPE_MASK));
+
+		for (flag, name) in [
+			(TEXTURE_ARRAY_FLAG, "array"),
+			(TEXTURE_MULTISAMPLE_FLAG, "multisample"),
+			(TEXTURE_FEEDBACK_FLAG, "feedback"),
+		] {
+			if shape & flag != 0 {
+				self.maybe_comma();
+				self.write_display(format_args!("\"{name}\": true"));
+			}
+		}
+
+		if access != ResourceAccess::Read {
+			self.maybe_comma();
+			self.write("\"access\": ");
+			self.write_string(access_name(access));
+		}
+	}
+
+	fn type_parameter(&mut self, type_parameter: TypeParameter<'_>) {
+		self.write("{\n");
+		self.indent += 1;
+
+		self.name(type_parameter.name());
+		self.write(",\n\"constraints\": \n[\n");
+		self.indent += 1;
+
+		for (i, constraint) in type_parameter.constraints().enumerate() {
+			if i != 0 {
+				self.write(",\n");
+			}
+			self.ty(constraint);
+		}
+
+		self.indent -= 1;
+		self.write("\n]");
+
+		self.indent -= 1;
+		self.write("\n}");
+	}
+
+	fn user_attributes<'a>(
+		&mut self,
+		attributes: impl ExactSizeIterator<Item = UserAttribute<'a>>,
+	) {
+		if attributes.len() == 0 {
+			return;
+		}
+
+		self.maybe_comma();
+		self.write("\"userAttribs\": [\n");
+		self.indent += 1;
+
+		for (i, attribute) in attributes.enumerate() {
+			if i != 0 {
+				self.write(",\n");
+			}
+			self.user_attribute(attribute);
+		}
+
+		self.indent -= 1;
+		self.write("\n]");
+	}
+
+	fn user_attribute(&mut self, attribute: UserAttribute<'_>) {
+		self.write("{\n");
+		self.indent += 1;
+
+		self.name(attribute.name());
+		self.write(",\n\"arguments\": [\n");
+		self.indent += 1;
+
+		for i in 0..attribute.argument_count() {
+			if i != 0 {
+				self.write(",\n");
+			}
+
+			if let Some(value) = attribute.argument_value_int(i) {
+				self.write_display(value);
+			} else if let Some(value) = attribute.argument_value_string(i) {
+				self.write_string(value);
+			} else if let Some(value) = attribute.argument_value_float(i) {
+				self.write_display(value);
+			} else {
+				self.write("null");
+			}
+		}
+
+		self.indent -= 1;
+		self.write("\n]\n");
+
+		self.indent -= 1;
+		self.write("}");
+	}
+
+	fn name(&mut self, name: &str) {
+		self.write("\"name\": ");
+		self.write_string(name);
+	}
+}
+
+fn is_structured_buffer(ty: Type<'_>) -> bool {
+	ty.resource_shape() as u32 & RESOURCE_BASE_SHAPE_MASK
+		== crate::ResourceShape::SlangStructuredBuffer as u32
+}
+
+fn stage_name(stage: Stage) -> Option<&'static str> {
+	Some(match stage {
+		Stage::Vertex => "vertex",
+		Stage::Hull => "hull",
+		Stage::Domain => "domain",
+		Stage::Geometry => "geometry",
+		Stage::Fragment => "fragment",
+		Stage::Compute => "compute",
+		Stage::RayGeneration => "raygeneration",
+		Stage::Intersection => "intersection",
+		Stage::AnyHit => "anyhit",
+		Stage::ClosestHit => "closesthit",
+		Stage::Miss => "miss",
+		Stage::Callable => "callable",
+		Stage::Mesh => "mesh",
+		Stage::Amplification => "amplification",
+		_ => return None,
+	})
+}
+
+fn category_name(category: ParameterCategory) -> &'static str {
+	match category {
+		ParameterCategory::ConstantBuffer => "constantBuffer",
+		ParameterCategory::ShaderResource => "shaderResource",
+		ParameterCategory::UnorderedAccess => "unorderedAccess",
+		ParameterCategory::VaryingInput => "varyingInput",
+		ParameterCategory::VaryingOutput => "varyingOutput",
+		ParameterCategory::SamplerState => "samplerState",
+		ParameterCategory::Uniform => "uniform",
+		ParameterCategory::PushConstantBuffer => "pushConstantBuffer",
+		ParameterCategory::DescriptorTableSlot => "descriptorTableSlot",
+		ParameterCategory::SpecializationConstant => "specializationConstant",
+		ParameterCategory::Mixed => "mixed",
+		ParameterCategory::RegisterSpace => "registerSpace",
+		ParameterCategory::SubElementRegisterSpace => "subElementRegisterSpace",
+		ParameterCategory::Generic => "generic",
+		ParameterCategory::MetalArgumentBufferElement => "metalArgumentBufferElement",
+		_ => "unknown",
+	}
+}
+
+fn scalar_type_name(scalar_type: ScalarType) -> &'static str {
+	match scalar_type {
+		ScalarType::Void => "void",
+		ScalarType::Bool => "bool",
+		ScalarType::Int8 => "int8",
+		ScalarType::Uint8 => "uint8",
+		ScalarType::Int16 => "int16",
+		ScalarType::Uint16 => "uint16",
+		ScalarType::Int32 => "int32",
+		ScalarType::Uint32 => "uint32",
+		ScalarType::Int64 => "int64",
+		ScalarType::Uint64 => "uint64",
+		ScalarType::Float16 => "float16",
+		ScalarType::Float32 => "float32",
+		ScalarType::Float64 => "float64",
+		_ => "unknown",
+	}
+}
+
+fn base_shape_name(base_shape: u32) -> &'static str {
+	use crate::ResourceShape as Shape;
+
+	match base_shape {
+		s if s == Shape::SlangTexture1d as u32 => "texture1D",
+		s if s == Shape::SlangTexture2d as u32 => "texture2D",
+		s if s == Shape::SlangTexture3d as u32 => "texture3D",
+		s if s == Shape::SlangTextureCube as u32 => "textureCube",
+		s if s == Shape::SlangTextureBuffer as u32 => "textureBuffer",
+		s if s == Shape::SlangStructuredBuffer as u32 => "structuredBuffer",
+		s if s == Shape::SlangByteAddressBuffer as u32 => "byteAddressBuffer",
+		_ => "unknown",
+	}
+}
+
+fn access_name(access: ResourceAccess) -> &'static str {
+	match access {
+		ResourceAccess::Write => "write",
+		ResourceAccess::ReadWrite => "readWrite",
+		ResourceAccess::RasterOrdered => "rasterOrdered",
+		ResourceAccess::Append => "append",
+		ResourceAccess::Consume => "consume",
+		ResourceAccess::Feedback => "feedback",
+		_ => "unknown",
+	}
+}
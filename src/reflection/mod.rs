@@ -1,38 +1,79 @@
 mod decl;
+mod descriptor_layout;
 mod entry_point;
 mod function;
 mod generic;
+mod json;
 mod shader;
+#[cfg(feature = "serde")]
+mod snapshot;
 mod ty;
 mod type_parameter;
 mod user_attribute;
 mod variable;
+mod varying;
 
 pub use decl::Decl;
+pub use descriptor_layout::{
+	BindingConflict, DescriptorBinding, DescriptorLayout, DescriptorSetLayout, PushConstantRange,
+	StageFlags,
+};
 pub use entry_point::EntryPoint;
 pub use function::Function;
 pub use generic::Generic;
 pub use shader::{compute_string_hash, Shader};
+#[cfg(feature = "serde")]
+pub use slang_reflection::{
+	AttributeArgument, BindingRangeReflection, CategoryOffset, CategorySize,
+	DescriptorRangeReflection, DescriptorSetReflection, EntryPointReflection, ProgramReflection,
+	TypeLayoutReflection, UserAttributeReflection, VariableLayoutReflection,
+};
 pub use ty::{Type, TypeLayout};
 pub use type_parameter::TypeParameter;
 pub use user_attribute::UserAttribute;
 pub use variable::{Variable, VariableLayout};
+pub use varying::{VaryingAttribute, VaryingFormat};
+
+/// Declares a reflection handle. Handles are plain pointers into the reflection data of a
+/// program and borrow the `ComponentType` (or `Session`) they were obtained from for `'prog`.
+macro_rules! handle {
+	($name:ident, $raw:ty) => {
+		#[derive(Clone, Copy)]
+		pub struct $name<'prog> {
+			ptr: std::ptr::NonNull<$raw>,
+			_phantom: std::marker::PhantomData<&'prog ()>,
+		}
+
+		impl $name<'_> {
+			/// # Safety
+			///
+			/// `ptr` must be null or stay valid for the chosen lifetime.
+			pub(crate) unsafe fn from_raw(ptr: *mut $raw) -> Option<Self> {
+				std::ptr::NonNull::new(ptr).map(|ptr| Self {
+					ptr,
+					_phantom: std::marker::PhantomData,
+				})
+			}
+
+			pub(crate) fn as_raw(self) -> *mut $raw {
+				self.ptr.as_ptr()
+			}
+		}
+	};
+}
 
 macro_rules! rcall {
 	($f:ident($s:ident $(,$arg:expr)*)) => {
-		unsafe { sys::$f($s as *const _ as *mut _ $(,$arg)*) }
+		unsafe { sys::$f($s.as_raw() as _ $(,$arg)*) }
 	};
 
-	($f:ident($s:ident $(,$arg:expr)*) as Option<&$cast:ty>) => {
-		unsafe {
-			let ptr = sys::$f($s as *const _ as *mut _ $(,$arg)*);
-			(!ptr.is_null()).then(|| &*(ptr as *const $cast))
-		}
+	($f:ident($s:ident $(,$arg:expr)*) as Option<$cast:ident>) => {
+		unsafe { $cast::from_raw(sys::$f($s.as_raw() as _ $(,$arg)*) as _) }
 	};
 
-	($f:ident($s:ident $(,$arg:expr)*) as &$cast:ty) => {
-		unsafe { &*(sys::$f($s as *const _ as *mut _ $(,$arg)*) as *const $cast) }
+	($f:ident($s:ident $(,$arg:expr)*) as $cast:ident) => {
+		unsafe { $cast::from_raw(sys::$f($s.as_raw() as _ $(,$arg)*) as _).unwrap() }
 	};
 }
 
-pub(super) use rcall;
+pub(super) use {handle, rcall};
@@ -1,111 +1,114 @@
 use super::{
-	rcall, EntryPoint, Function, Type, TypeLayout, TypeParameter, Variable, VariableLayout,
+	handle, rcall, EntryPoint, Function, Type, TypeLayout, TypeParameter, Variable, VariableLayout,
 };
 use slang_sys as sys;
 
-#[repr(transparent)]
-pub struct Shader(sys::SlangReflection);
+handle!(Shader, sys::SlangReflection);
 
-impl Shader {
-	pub fn parameter_count(&self) -> u32 {
+impl<'prog> Shader<'prog> {
+	pub fn parameter_count(self) -> u32 {
 		rcall!(spReflection_GetParameterCount(self))
 	}
 
-	pub fn parameter_by_index(&self, index: u32) -> Option<&VariableLayout> {
-		rcall!(spReflection_GetParameterByIndex(self, index) as Option<&VariableLayout>)
+	pub fn parameter_by_index(self, index: u32) -> Option<VariableLayout<'prog>> {
+		rcall!(spReflection_GetParameterByIndex(self, index) as Option<VariableLayout>)
 	}
 
-	pub fn parameters(&self) -> impl ExactSizeIterator<Item = &VariableLayout> {
+	pub fn parameters(self) -> impl ExactSizeIterator<Item = VariableLayout<'prog>> {
 		(0..self.parameter_count())
-			.map(move |i| rcall!(spReflection_GetParameterByIndex(self, i) as &VariableLayout))
+			.map(move |i| rcall!(spReflection_GetParameterByIndex(self, i) as VariableLayout))
 	}
 
-	pub fn type_parameter_count(&self) -> u32 {
+	pub fn type_parameter_count(self) -> u32 {
 		rcall!(spReflection_GetTypeParameterCount(self))
 	}
 
-	pub fn type_parameter_by_index(&self, index: u32) -> Option<&TypeParameter> {
-		rcall!(spReflection_GetTypeParameterByIndex(self, index) as Option<&TypeParameter>)
+	pub fn type_parameter_by_index(self, index: u32) -> Option<TypeParameter<'prog>> {
+		rcall!(spReflection_GetTypeParameterByIndex(self, index) as Option<TypeParameter>)
 	}
 
-	pub fn type_parameters(&self) -> impl ExactSizeIterator<Item = &TypeParameter> {
+	pub fn type_parameters(self) -> impl ExactSizeIterator<Item = TypeParameter<'prog>> {
 		(0..self.type_parameter_count())
-			.map(move |i| rcall!(spReflection_GetTypeParameterByIndex(self, i) as &TypeParameter))
+			.map(move |i| rcall!(spReflection_GetTypeParameterByIndex(self, i) as TypeParameter))
 	}
 
-	pub fn find_type_parameter_by_name(&self, name: &str) -> Option<&TypeParameter> {
+	pub fn find_type_parameter_by_name(self, name: &str) -> Option<TypeParameter<'prog>> {
 		let name = std::ffi::CString::new(name).unwrap();
-		rcall!(spReflection_FindTypeParameter(self, name.as_ptr()) as Option<&TypeParameter>)
+		rcall!(spReflection_FindTypeParameter(self, name.as_ptr()) as Option<TypeParameter>)
 	}
 
-	pub fn entry_point_count(&self) -> u32 {
+	pub fn entry_point_count(self) -> u32 {
 		rcall!(spReflection_getEntryPointCount(self)) as _
 	}
 
-	pub fn entry_point_by_index(&self, index: u32) -> Option<&EntryPoint> {
-		rcall!(spReflection_getEntryPointByIndex(self, index as _) as Option<&EntryPoint>)
+	pub fn entry_point_by_index(self, index: u32) -> Option<EntryPoint<'prog>> {
+		rcall!(spReflection_getEntryPointByIndex(self, index as _) as Option<EntryPoint>)
 	}
 
-	pub fn entry_points(&self) -> impl ExactSizeIterator<Item = &EntryPoint> {
+	pub fn entry_points(self) -> impl ExactSizeIterator<Item = EntryPoint<'prog>> {
 		(0..self.entry_point_count())
-			.map(move |i| rcall!(spReflection_getEntryPointByIndex(self, i as _) as &EntryPoint))
+			.map(move |i| rcall!(spReflection_getEntryPointByIndex(self, i as _) as EntryPoint))
 	}
 
-	pub fn find_entry_point_by_name(&self, name: &str) -> Option<&EntryPoint> {
+	pub fn find_entry_point_by_name(self, name: &str) -> Option<EntryPoint<'prog>> {
 		let name = std::ffi::CString::new(name).unwrap();
-		rcall!(spReflection_findEntryPointByName(self, name.as_ptr()) as Option<&EntryPoint>)
+		rcall!(spReflection_findEntryPointByName(self, name.as_ptr()) as Option<EntryPoint>)
 	}
 
-	pub fn global_constant_buffer_binding(&self) -> u64 {
+	pub fn global_constant_buffer_binding(self) -> u64 {
 		rcall!(spReflection_getGlobalConstantBufferBinding(self))
 	}
 
-	pub fn global_constant_buffer_size(&self) -> usize {
+	pub fn global_constant_buffer_size(self) -> usize {
 		rcall!(spReflection_getGlobalConstantBufferSize(self))
 	}
 
-	pub fn find_type_by_name(&self, name: &str) -> Option<&Type> {
+	pub fn find_type_by_name(self, name: &str) -> Option<Type<'prog>> {
 		let name = std::ffi::CString::new(name).unwrap();
-		rcall!(spReflection_FindTypeByName(self, name.as_ptr()) as Option<&Type>)
+		rcall!(spReflection_FindTypeByName(self, name.as_ptr()) as Option<Type>)
 	}
 
-	pub fn find_function_by_name(&self, name: &str) -> Option<&Function> {
+	pub fn find_function_by_name(self, name: &str) -> Option<Function<'prog>> {
 		let name = std::ffi::CString::new(name).unwrap();
-		rcall!(spReflection_FindFunctionByName(self, name.as_ptr()) as Option<&Function>)
+		rcall!(spReflection_FindFunctionByName(self, name.as_ptr()) as Option<Function>)
 	}
 
-	pub fn find_function_by_name_in_type(&self, ty: &Type, name: &str) -> Option<&Function> {
+	pub fn find_function_by_name_in_type(
+		self,
+		ty: Type<'_>,
+		name: &str,
+	) -> Option<Function<'prog>> {
 		let name = std::ffi::CString::new(name).unwrap();
 		rcall!(
-			spReflection_FindFunctionByNameInType(self, ty as *const _ as *mut _, name.as_ptr())
-				as Option<&Function>
+			spReflection_FindFunctionByNameInType(self, ty.as_raw(), name.as_ptr())
+				as Option<Function>
 		)
 	}
 
-	pub fn find_var_by_name_in_type(&self, ty: &Type, name: &str) -> Option<&Variable> {
+	pub fn find_var_by_name_in_type(self, ty: Type<'_>, name: &str) -> Option<Variable<'prog>> {
 		let name = std::ffi::CString::new(name).unwrap();
 		rcall!(
-			spReflection_FindVarByNameInType(self, ty as *const _ as *mut _, name.as_ptr())
-				as Option<&Variable>
+			spReflection_FindVarByNameInType(self, ty.as_raw(), name.as_ptr()) as Option<Variable>
 		)
 	}
 
-	pub fn type_layout(&self, ty: &Type, rules: sys::SlangLayoutRules) -> Option<&TypeLayout> {
-		rcall!(
-			spReflection_GetTypeLayout(self, ty as *const _ as *mut _, rules)
-				as Option<&TypeLayout>
-		)
+	pub fn type_layout(
+		self,
+		ty: Type<'_>,
+		rules: sys::SlangLayoutRules,
+	) -> Option<TypeLayout<'prog>> {
+		rcall!(spReflection_GetTypeLayout(self, ty.as_raw(), rules) as Option<TypeLayout>)
 	}
 
 	// TODO: specialize_type
 	// TODO: specialize_generic
 	// TODO: is_sub_type
 
-	pub fn hashed_string_count(&self) -> u64 {
+	pub fn hashed_string_count(self) -> u64 {
 		rcall!(spReflection_getHashedStringCount(self))
 	}
 
-	pub fn hashed_string(&self, index: u64) -> Option<&str> {
+	pub fn hashed_string(self, index: u64) -> Option<&'prog str> {
 		let mut len = 0;
 		let result = rcall!(spReflection_getHashedString(self, index, &mut len));
 
@@ -115,15 +118,15 @@ impl Shader {
 		})
 	}
 
-	pub fn global_params_type_layout(&self) -> &TypeLayout {
-		rcall!(spReflection_getGlobalParamsTypeLayout(self) as &TypeLayout)
+	pub fn global_params_type_layout(self) -> TypeLayout<'prog> {
+		rcall!(spReflection_getGlobalParamsTypeLayout(self) as TypeLayout)
 	}
 
-	pub fn global_params_var_layout(&self) -> &VariableLayout {
-		rcall!(spReflection_getGlobalParamsVarLayout(self) as &VariableLayout)
+	pub fn global_params_var_layout(self) -> VariableLayout<'prog> {
+		rcall!(spReflection_getGlobalParamsVarLayout(self) as VariableLayout)
 	}
 }
 
 pub fn compute_string_hash(string: &str) -> u32 {
-	rcall!(spComputeStringHash(string, string.len()))
+	unsafe { sys::spComputeStringHash(string.as_ptr() as _, string.len()) }
 }
@@ -0,0 +1,419 @@
+use slang_reflection::{
+	AttributeArgument, BindingRangeReflection, CategoryOffset, CategorySize,
+	DescriptorRangeReflection, DescriptorSetReflection, EntryPointReflection, ProgramReflection,
+	TypeLayoutReflection, UserAttributeReflection, VariableLayoutReflection,
+};
+
+use super::{EntryPoint, Shader, TypeLayout, UserAttribute, Variable, VariableLayout};
+use crate::TypeKind;
+
+impl From<Shader<'_>> for ProgramReflection {
+	fn from(shader: Shader<'_>) -> Self {
+		let global_params = shader.global_params_type_layout();
+
+		Self {
+			parameters: shader
+				.parameters()
+				.map(VariableLayoutReflection::from)
+				.collect(),
+			entry_points: shader
+				.entry_points()
+				.map(EntryPointReflection::from)
+				.collect(),
+			global_constant_buffer_binding: shader.global_constant_buffer_binding(),
+			global_constant_buffer_size: shader.global_constant_buffer_size(),
+			binding_ranges: binding_ranges(global_params),
+			descriptor_sets: descriptor_sets(global_params),
+		}
+	}
+}
+
+impl From<EntryPoint<'_>> for EntryPointReflection {
+	fn from(entry_point: EntryPoint<'_>) -> Self {
+		let type_layout = entry_point.type_layout();
+
+		Self {
+			name: entry_point.name().to_string(),
+			name_override: entry_point.name_override().map(str::to_string),
+			stage: entry_point.stage().mirror(),
+			compute_thread_group_size: entry_point.compute_thread_group_size(),
+			uses_any_sample_rate_input: entry_point.uses_any_sample_rate_input(),
+			has_default_constant_buffer: entry_point.has_default_constant_buffer(),
+			parameters: entry_point
+				.parameters()
+				.map(VariableLayoutReflection::from)
+				.collect(),
+			result: entry_point
+				.result_var_layout()
+				.map(VariableLayoutReflection::from),
+			binding_ranges: binding_ranges(type_layout),
+			descriptor_sets: descriptor_sets(type_layout),
+			user_attributes: entry_point
+				.function()
+				.user_attributes()
+				.map(UserAttributeReflection::from)
+				.collect(),
+		}
+	}
+}
+
+impl From<VariableLayout<'_>> for VariableLayoutReflection {
+	fn from(layout: VariableLayout<'_>) -> Self {
+		let type_layout = layout.type_layout();
+
+		// Result and parameter block layouts don't always have a variable.
+		let variable = layout.variable();
+
+		Self {
+			name: variable.and_then(Variable::name).map(str::to_string),
+			offsets: type_layout
+				.categories()
+				.map(|category| CategoryOffset {
+					category: category.mirror(),
+					offset: layout.offset(category),
+					space: layout.binding_space_with_category(category),
+				})
+				.collect(),
+			semantic_name: layout.semantic_name().map(str::to_string),
+			semantic_index: layout.semantic_index(),
+			user_attributes: variable.map_or_else(Vec::new, |variable| {
+				variable
+					.user_attributes()
+					.map(UserAttributeReflection::from)
+					.collect()
+			}),
+			type_layout: TypeLayoutReflection::from(type_layout),
+		}
+	}
+}
+
+impl From<TypeLayout<'_>> for TypeLayoutReflection {
+	fn from(layout: TypeLayout<'_>) -> Self {
+		let ty = layout.ty();
+		let kind = layout.kind();
+
+		// Pointers can refer to the type that contains them, so their target isn't followed.
+		let element_type_layout = match kind {
+			TypeKind::Pointer => None,
+			_ => layout.element_type_layout(),
+		};
+
+		Self {
+			kind: kind.mirror(),
+			name: ty.name().map(str::to_string),
+			sizes: layout
+				.categories()
+				.map(|category| CategorySize {
+					category: category.mirror(),
+					size: layout.size(category),
+					alignment: layout.alignment(category),
+					stride: layout.stride(category),
+				})
+				.collect(),
+			fields: layout
+				.fields()
+				.map(VariableLayoutReflection::from)
+				.collect(),
+			element_count: match kind {
+				TypeKind::Array => ty.element_count(),
+				_ => 0,
+			},
+			element_type_layout: element_type_layout.map(|layout| Box::new(Self::from(layout))),
+			row_count: ty.row_count(),
+			column_count: ty.column_count(),
+			scalar_type: ty.scalar_type().mirror(),
+			matrix_layout_mode: layout.matrix_layout_mode().mirror(),
+			resource_shape: ty.resource_shape().mirror(),
+			resource_access: ty.resource_access().mirror(),
+			user_attributes: ty
+				.user_attributes()
+				.map(UserAttributeReflection::from)
+				.collect(),
+		}
+	}
+}
+
+impl From<UserAttribute<'_>> for UserAttributeReflection {
+	fn from(attribute: UserAttribute<'_>) -> Self {
+		let arguments = (0..attribute.argument_count())
+			.map(|i| {
+				if let Some(value) = attribute.argument_value_int(i) {
+					AttributeArgument::Int(value)
+				} else if let Some(value) = attribute.argument_value_float(i) {
+					AttributeArgument::Float(value)
+				} else if let Some(value) = attribute.argument_value_string(i) {
+					AttributeArgument::String(value.to_string())
+				} else {
+					AttributeArgument::Unknown
+				}
+			})
+			.collect();
+
+		Self {
+			name: attribute.name().to_string(),
+			arguments,
+		}
+	}
+}
+
+fn binding_ranges(layout: TypeLayout<'_>) -> Vec<BindingRangeReflection> {
+	(0..layout.binding_range_count())
+		.map(|i| BindingRangeReflection {
+			binding_type: layout.binding_range_type(i).mirror(),
+			binding_count: layout.binding_range_binding_count(i),
+			image_format: layout.binding_range_image_format(i).mirror(),
+			is_specializable: layout.is_binding_range_specializable(i),
+			descriptor_set_index: layout.binding_range_descriptor_set_index(i),
+			first_descriptor_range_index: layout.binding_range_first_descriptor_range_index(i),
+			descriptor_range_count: layout.binding_range_descriptor_range_count(i),
+		})
+		.collect()
+}
+
+fn descriptor_sets(layout: TypeLayout<'_>) -> Vec<DescriptorSetReflection> {
+	(0..layout.descriptor_set_count())
+		.map(|set| DescriptorSetReflection {
+			space_offset: layout.descriptor_set_space_offset(set),
+			descriptor_ranges: (0..layout.descriptor_set_descriptor_range_count(set))
+				.map(|range| DescriptorRangeReflection {
+					index_offset: layout.descriptor_set_descriptor_range_index_offset(set, range),
+					descriptor_count: layout
+						.descriptor_set_descriptor_range_descriptor_count(set, range),
+					binding_type: layout
+						.descriptor_set_descriptor_range_type(set, range)
+						.mirror(),
+					category: layout
+						.descriptor_set_descriptor_range_category(set, range)
+						.mirror(),
+				})
+				.collect(),
+		})
+		.collect()
+}
+
+/// Converts a Slang enum to its copy in `slang_reflection`.
+trait Mirror<T> {
+	fn mirror(self) -> T;
+}
+
+macro_rules! mirror {
+	($($name:ident { $($variant:ident),* $(,)? })*) => {
+		$(
+			impl Mirror<slang_reflection::$name> for crate::$name {
+				fn mirror(self) -> slang_reflection::$name {
+					match self {
+						$(Self::$variant => slang_reflection::$name::$variant,)*
+					}
+				}
+			}
+		)*
+	};
+}
+
+mirror! {
+	Stage {
+		None,
+		Vertex,
+		Hull,
+		Domain,
+		Geometry,
+		Fragment,
+		Compute,
+		RayGeneration,
+		Intersection,
+		AnyHit,
+		ClosestHit,
+		Miss,
+		Callable,
+		Mesh,
+		Amplification,
+		Count,
+	}
+
+	ParameterCategory {
+		None,
+		Mixed,
+		ConstantBuffer,
+		ShaderResource,
+		UnorderedAccess,
+		VaryingInput,
+		VaryingOutput,
+		SamplerState,
+		Uniform,
+		DescriptorTableSlot,
+		SpecializationConstant,
+		PushConstantBuffer,
+		RegisterSpace,
+		Generic,
+		RayPayload,
+		HitAttributes,
+		CallablePayload,
+		ShaderRecord,
+		ExistentialTypeParam,
+		ExistentialObjectParam,
+		SubElementRegisterSpace,
+		Subpass,
+		MetalArgumentBufferElement,
+		MetalAttribute,
+		MetalPayload,
+		Count,
+	}
+
+	TypeKind {
+		None,
+		Struct,
+		Array,
+		Matrix,
+		Vector,
+		Scalar,
+		ConstantBuffer,
+		Resource,
+		SamplerState,
+		TextureBuffer,
+		ShaderStorageBuffer,
+		ParameterBlock,
+		GenericTypeParameter,
+		Interface,
+		OutputStream,
+		MeshOutput,
+		Specialized,
+		Feedback,
+		Pointer,
+		DynamicResource,
+		Count,
+	}
+
+	ScalarType {
+		None,
+		Void,
+		Bool,
+		Int32,
+		Uint32,
+		Int64,
+		Uint64,
+		Float16,
+		Float32,
+		Float64,
+		Int8,
+		Uint8,
+		Int16,
+		Uint16,
+		Intptr,
+		Uintptr,
+	}
+
+	MatrixLayoutMode {
+		ModeUnknown,
+		RowMajor,
+		ColumnMajor,
+	}
+
+	ResourceShape {
+		SlangResourceBaseShapeMask,
+		SlangResourceNone,
+		SlangTexture1d,
+		SlangTexture2d,
+		SlangTexture3d,
+		SlangTextureCube,
+		SlangTextureBuffer,
+		SlangStructuredBuffer,
+		SlangByteAddressBuffer,
+		SlangResourceUnknown,
+		SlangAccelerationStructure,
+		SlangTextureSubpass,
+		SlangResourceExtShapeMask,
+		SlangTextureFeedbackFlag,
+		SlangTextureShadowFlag,
+		SlangTextureArrayFlag,
+		SlangTextureMultisampleFlag,
+		SlangTexture1dArray,
+		SlangTexture2dArray,
+		SlangTextureCubeArray,
+		SlangTexture2dMultisample,
+		SlangTexture2dMultisampleArray,
+		SlangTextureSubpassMultisample,
+	}
+
+	ResourceAccess {
+		None,
+		Read,
+		ReadWrite,
+		RasterOrdered,
+		Append,
+		Consume,
+		Write,
+		Feedback,
+		Unknown,
+	}
+
+	BindingType {
+		Unknown,
+		Sampler,
+		Texture,
+		ConstantBuffer,
+		ParameterBlock,
+		TypedBuffer,
+		RawBuffer,
+		CombinedTextureSampler,
+		InputRenderTarget,
+		InlineUniformData,
+		RayTracingAccelerationStructure,
+		VaryingInput,
+		VaryingOutput,
+		ExistentialValue,
+		PushConstant,
+		MutableFlag,
+		MutableTeture,
+		MutableTypedBuffer,
+		MutableRawBuffer,
+		BaseMask,
+		ExtMask,
+	}
+
+	ImageFormat {
+		SLANGIMAGEFORMATUnknown,
+		SLANGIMAGEFORMATRgba32f,
+		SLANGIMAGEFORMATRgba16f,
+		SLANGIMAGEFORMATRg32f,
+		SLANGIMAGEFORMATRg16f,
+		SLANGIMAGEFORMATR11fG11fB10f,
+		SLANGIMAGEFORMATR32f,
+		SLANGIMAGEFORMATR16f,
+		SLANGIMAGEFORMATRgba16,
+		SLANGIMAGEFORMATRgb10A2,
+		SLANGIMAGEFORMATRgba8,
+		SLANGIMAGEFORMATRg16,
+		SLANGIMAGEFORMATRg8,
+		SLANGIMAGEFORMATR16,
+		SLANGIMAGEFORMATR8,
+		SLANGIMAGEFORMATRgba16Snorm,
+		SLANGIMAGEFORMATRgba8Snorm,
+		SLANGIMAGEFORMATRg16Snorm,
+		SLANGIMAGEFORMATRg8Snorm,
+		SLANGIMAGEFORMATR16Snorm,
+		SLANGIMAGEFORMATR8Snorm,
+		SLANGIMAGEFORMATRgba32i,
+		SLANGIMAGEFORMATRgba16i,
+		SLANGIMAGEFORMATRgba8i,
+		SLANGIMAGEFORMATRg32i,
+		SLANGIMAGEFORMATRg16i,
+		SLANGIMAGEFORMATRg8i,
+		SLANGIMAGEFORMATR32i,
+		SLANGIMAGEFORMATR16i,
+		SLANGIMAGEFORMATR8i,
+		SLANGIMAGEFORMATRgba32ui,
+		SLANGIMAGEFORMATRgba16ui,
+		SLANGIMAGEFORMATRgb10A2ui,
+		SLANGIMAGEFORMATRgba8ui,
+		SLANGIMAGEFORMATRg32ui,
+		SLANGIMAGEFORMATRg16ui,
+		SLANGIMAGEFORMATRg8ui,
+		SLANGIMAGEFORMATR32ui,
+		SLANGIMAGEFORMATR16ui,
+		SLANGIMAGEFORMATR8ui,
+		SLANGIMAGEFORMATR64ui,
+		SLANGIMAGEFORMATR64i,
+		SLANGIMAGEFORMATBgra8,
+		SLANGIMAGEFORMATCount,
+	}
+}
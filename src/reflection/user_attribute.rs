@@ -1,24 +1,23 @@
-use super::{rcall, Type};
+use super::{handle, rcall, Type};
 use slang_sys as sys;
 
-#[repr(transparent)]
-pub struct UserAttribute(sys::SlangReflectionUserAttribute);
+handle!(UserAttribute, sys::SlangReflectionUserAttribute);
 
-impl UserAttribute {
-	pub fn name(&self) -> &str {
+impl<'prog> UserAttribute<'prog> {
+	pub fn name(self) -> &'prog str {
 		let name = rcall!(spReflectionUserAttribute_GetName(self));
 		unsafe { std::ffi::CStr::from_ptr(name).to_str().unwrap() }
 	}
 
-	pub fn argument_count(&self) -> u32 {
+	pub fn argument_count(self) -> u32 {
 		rcall!(spReflectionUserAttribute_GetArgumentCount(self))
 	}
 
-	pub fn argument_type(&self, index: u32) -> &Type {
-		rcall!(spReflectionUserAttribute_GetArgumentType(self, index) as &Type)
+	pub fn argument_type(self, index: u32) -> Type<'prog> {
+		rcall!(spReflectionUserAttribute_GetArgumentType(self, index) as Type)
 	}
 
-	pub fn argument_value_int(&self, index: u32) -> Option<i32> {
+	pub fn argument_value_int(self, index: u32) -> Option<i32> {
 		let mut out = 0;
 		let result = rcall!(spReflectionUserAttribute_GetArgumentValueInt(
 			self, index, &mut out
@@ -27,7 +26,7 @@ impl UserAttribute {
 		crate::succeeded(result).then(|| out)
 	}
 
-	pub fn argument_value_float(&self, index: u32) -> Option<f32> {
+	pub fn argument_value_float(self, index: u32) -> Option<f32> {
 		let mut out = 0.0;
 		let result = rcall!(spReflectionUserAttribute_GetArgumentValueFloat(
 			self, index, &mut out
@@ -36,7 +35,7 @@ impl UserAttribute {
 		crate::succeeded(result).then(|| out)
 	}
 
-	pub fn argument_value_string(&self, index: u32) -> Option<&str> {
+	pub fn argument_value_string(self, index: u32) -> Option<&'prog str> {
 		let mut len = 0;
 		let result = rcall!(spReflectionUserAttribute_GetArgumentValueString(
 			self, index, &mut len
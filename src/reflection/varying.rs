@@ -0,0 +1,139 @@
+use super::{EntryPoint, TypeLayout, Variable, VariableLayout};
+use crate::{MatrixLayoutMode, ParameterCategory, ScalarType, TypeKind};
+
+/// A vertex attribute or fragment output, one per location.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VaryingAttribute<'prog> {
+	/// The name of the parameter or struct field.
+	pub name: Option<&'prog str>,
+	pub semantic: Option<&'prog str>,
+	/// Arrays and matrices that span several locations get consecutive indices.
+	pub semantic_index: usize,
+	pub location: usize,
+	pub format: VaryingFormat,
+}
+
+/// A scalar or vector, e.g. `Float32` with 3 components for `float3`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VaryingFormat {
+	pub scalar_type: ScalarType,
+	pub components: u32,
+}
+
+impl<'prog> EntryPoint<'prog> {
+	/// The varying inputs, i.e. the vertex attributes of vertex shaders. Struct parameters are
+	/// flattened and system values like `SV_VertexID` are left out.
+	pub fn vertex_inputs(self) -> Vec<VaryingAttribute<'prog>> {
+		let mut attributes = Vec::new();
+
+		for parameter in self.parameters() {
+			collect(
+				parameter,
+				ParameterCategory::VaryingInput,
+				0,
+				&mut attributes,
+			);
+		}
+
+		attributes
+	}
+
+	/// The render targets written by fragment shaders, from the return value.
+	pub fn fragment_outputs(self) -> Vec<VaryingAttribute<'prog>> {
+		let mut attributes = Vec::new();
+
+		if let Some(result) = self.result_var_layout() {
+			collect(result, ParameterCategory::VaryingOutput, 0, &mut attributes);
+		}
+
+		attributes
+	}
+}
+
+fn collect<'prog>(
+	var: VariableLayout<'prog>,
+	category: ParameterCategory,
+	location: usize,
+	attributes: &mut Vec<VaryingAttribute<'prog>>,
+) {
+	let layout = var.type_layout();
+
+	if !layout.categories().any(|c| c == category) {
+		return;
+	}
+
+	let location = location + var.offset(category);
+
+	if layout.kind() == TypeKind::Struct {
+		for field in layout.fields() {
+			collect(field, category, location, attributes);
+		}
+		return;
+	}
+
+	let attribute = VaryingAttribute {
+		// The result layout has no variable.
+		name: var.variable().and_then(Variable::name),
+		semantic: var.semantic_name(),
+		semantic_index: var.semantic_index(),
+		location,
+		format: VaryingFormat {
+			scalar_type: ScalarType::None,
+			components: 0,
+		},
+	};
+
+	push_locations(layout, category, attribute, attributes);
+}
+
+/// Adds an attribute for each location of `layout`, starting at the location of `attribute`.
+fn push_locations<'prog>(
+	layout: TypeLayout<'prog>,
+	category: ParameterCategory,
+	attribute: VaryingAttribute<'prog>,
+	attributes: &mut Vec<VaryingAttribute<'prog>>,
+) {
+	let ty = layout.ty();
+
+	let (count, components) = match layout.kind() {
+		TypeKind::Array => {
+			let Some(element) = layout.element_type_layout() else {
+				return;
+			};
+			let stride = layout.element_stride(category);
+
+			for index in 0..layout.element_count() {
+				let attribute = VaryingAttribute {
+					semantic_index: attribute.semantic_index + index * stride,
+					location: attribute.location + index * stride,
+					..attribute.clone()
+				};
+				push_locations(element, category, attribute, attributes);
+			}
+
+			return;
+		}
+		// Each location holds a row or a column, depending on the layout mode.
+		TypeKind::Matrix if layout.matrix_layout_mode() == MatrixLayoutMode::RowMajor => {
+			(ty.row_count(), ty.column_count())
+		}
+		TypeKind::Matrix => (ty.column_count(), ty.row_count()),
+		TypeKind::Vector => (1, ty.element_count() as u32),
+		TypeKind::Scalar => (1, 1),
+		_ => return,
+	};
+
+	let format = VaryingFormat {
+		scalar_type: ty.scalar_type(),
+		components,
+	};
+
+	for index in 0..count as usize {
+		attributes.push(VaryingAttribute {
+			semantic_index: attribute.semantic_index + index,
+			location: attribute.location + index,
+			format,
+			..attribute.clone()
+		});
+	}
+}
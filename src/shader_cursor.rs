@@ -0,0 +1,426 @@
+use std::fmt;
+
+use crate::reflection::TypeLayout;
+use crate::{BindingType, MatrixLayoutMode, ParameterCategory, ScalarType, TypeKind};
+
+/// The uniform data and resource bindings of a struct, constant buffer or parameter block,
+/// filled in through a [`ShaderCursor`]. `R` is whatever the renderer uses to refer to resources.
+pub struct ShaderData<'prog, R> {
+	layout: TypeLayout<'prog>,
+	uniforms: Vec<u8>,
+	bindings: Vec<ResourceBinding<R>>,
+}
+
+/// Where a resource set through [`ShaderCursor::set_resource`] is bound.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResourceBinding<R> {
+	pub resource: R,
+	/// Binding range of the layout the [`ShaderData`] was created with.
+	pub binding_range: i64,
+	/// Index into the binding range for arrays of resources.
+	pub array_index: usize,
+	pub binding_type: BindingType,
+	/// Index of the descriptor set within the layout, `-1` if the range isn't in one.
+	pub descriptor_set: i64,
+	/// The space (Vulkan descriptor set or D3D register space) of the descriptor set.
+	pub space: i64,
+	/// The binding (Vulkan) or register (D3D) of the resource, including `array_index`.
+	pub index: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CursorError {
+	NoSuchField(String),
+	NotAStruct,
+	NotAnArray,
+	IndexOutOfRange {
+		index: usize,
+		count: usize,
+	},
+	/// The value doesn't match the type the cursor points at.
+	TypeMismatch,
+	SizeMismatch {
+		expected: usize,
+		actual: usize,
+	},
+	NotAResource,
+	InvalidPath(String),
+}
+
+impl fmt::Display for CursorError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::NoSuchField(name) => write!(f, "no field named `{name}`"),
+			Self::NotAStruct => write!(f, "not a struct"),
+			Self::NotAnArray => write!(f, "not an array"),
+			Self::IndexOutOfRange { index, count } => {
+				write!(f, "index {index} is out of range for {count} elements")
+			}
+			Self::TypeMismatch => write!(f, "the value doesn't match the shader type"),
+			Self::SizeMismatch { expected, actual } => {
+				write!(f, "expected at most {expected} bytes, got {actual}")
+			}
+			Self::NotAResource => write!(f, "not a resource"),
+			Self::InvalidPath(path) => write!(f, "invalid path `{path}`"),
+		}
+	}
+}
+
+impl<'prog, R> ShaderData<'prog, R> {
+	/// Constant buffers and parameter blocks are filled in with the layout of their contents.
+	pub fn new(layout: TypeLayout<'prog>) -> Self {
+		let layout = match layout.kind() {
+			TypeKind::ConstantBuffer | TypeKind::ParameterBlock => {
+				layout.element_type_layout().unwrap_or(layout)
+			}
+			_ => layout,
+		};
+
+		Self {
+			layout,
+			uniforms: vec![0; layout.size(ParameterCategory::Uniform)],
+			bindings: Vec::new(),
+		}
+	}
+
+	pub fn type_layout(&self) -> TypeLayout<'prog> {
+		self.layout
+	}
+
+	pub fn cursor(&mut self) -> ShaderCursor<'_, 'prog, R> {
+		let layout = self.layout;
+
+		ShaderCursor {
+			data: self,
+			target: Ok(Target {
+				layout,
+				uniform_offset: 0,
+				binding_range: 0,
+				array_index: 0,
+			}),
+		}
+	}
+
+	pub fn uniforms(&self) -> &[u8] {
+		&self.uniforms
+	}
+
+	pub fn bindings(&self) -> &[ResourceBinding<R>] {
+		&self.bindings
+	}
+
+	pub fn into_parts(self) -> (Vec<u8>, Vec<ResourceBinding<R>>) {
+		(self.uniforms, self.bindings)
+	}
+}
+
+#[derive(Clone, Copy)]
+struct Target<'prog> {
+	layout: TypeLayout<'prog>,
+	uniform_offset: usize,
+	binding_range: i64,
+	array_index: usize,
+}
+
+/// Points at a part of a [`ShaderData`].
+///
+/// Navigation doesn't fail right away, so paths can be chained like
+/// `cursor.field("material").field("albedo").index(3)`. The setters report the first error.
+pub struct ShaderCursor<'a, 'prog, R> {
+	data: &'a mut ShaderData<'prog, R>,
+	target: Result<Target<'prog>, CursorError>,
+}
+
+impl<'a, 'prog, R> ShaderCursor<'a, 'prog, R> {
+	/// The layout of what the cursor points at.
+	pub fn type_layout(&self) -> Result<TypeLayout<'prog>, CursorError> {
+		self.target.clone().map(|target| target.layout)
+	}
+
+	pub fn uniform_offset(&self) -> Result<usize, CursorError> {
+		self.target.clone().map(|target| target.uniform_offset)
+	}
+
+	pub fn field(mut self, name: &str) -> Self {
+		self.target = self.target.and_then(|target| {
+			let layout = target.layout;
+
+			if layout.kind() != TypeKind::Struct {
+				return Err(CursorError::NotAStruct);
+			}
+
+			let (index, field) = layout
+				.fields()
+				.enumerate()
+				.find(|(_, field)| field.variable().and_then(|v| v.name()) == Some(name))
+				.ok_or_else(|| CursorError::NoSuchField(name.to_string()))?;
+
+			Ok(Target {
+				layout: field.type_layout(),
+				uniform_offset: target.uniform_offset + field.offset(ParameterCategory::Uniform),
+				binding_range: target.binding_range
+					+ layout.field_binding_range_offset(index as i64),
+				array_index: target.array_index,
+			})
+		});
+
+		self
+	}
+
+	pub fn index(mut self, index: usize) -> Self {
+		self.target = self.target.and_then(|target| {
+			let layout = target.layout;
+
+			let element = match layout.kind() {
+				TypeKind::Array => layout.element_type_layout(),
+				_ => None,
+			}
+			.ok_or(CursorError::NotAnArray)?;
+
+			let count = layout.element_count();
+
+			let out_of_range = CursorError::IndexOutOfRange { index, count };
+
+			// Unsized arrays report `usize::MAX` elements, offsets into them can overflow.
+			if index >= count {
+				return Err(out_of_range);
+			}
+
+			let uniform_offset = index
+				.checked_mul(layout.element_stride(ParameterCategory::Uniform))
+				.and_then(|offset| offset.checked_add(target.uniform_offset))
+				.ok_or(out_of_range.clone())?;
+
+			let array_index = target
+				.array_index
+				.checked_mul(count)
+				.and_then(|array_index| array_index.checked_add(index))
+				.ok_or(out_of_range)?;
+
+			Ok(Target {
+				layout: element,
+				uniform_offset,
+				binding_range: target.binding_range,
+				array_index,
+			})
+		});
+
+		self
+	}
+
+	/// Follows a path of fields and indices, like `lights[2].color`.
+	pub fn path(self, path: &str) -> Self {
+		let mut cursor = self;
+
+		for part in path.split('.') {
+			let (name, mut indices) = part.split_once('[').unwrap_or((part, ""));
+
+			if !name.is_empty() {
+				cursor = cursor.field(name);
+			}
+
+			while !indices.is_empty() {
+				let parsed = indices
+					.split_once(']')
+					.and_then(|(index, rest)| Some((index.parse().ok()?, rest)));
+
+				let Some((index, rest)) = parsed else {
+					cursor.target = Err(CursorError::InvalidPath(path.to_string()));
+					return cursor;
+				};
+
+				cursor = cursor.index(index);
+				indices = rest.strip_prefix('[').unwrap_or(rest);
+			}
+		}
+
+		cursor
+	}
+
+	/// Writes raw bytes to the uniform data, they may cover only the start of the value.
+	pub fn set_bytes(&mut self, bytes: &[u8]) -> Result<(), CursorError> {
+		let target = self.target.clone()?;
+		let size = target.layout.size(ParameterCategory::Uniform);
+
+		if bytes.len() > size {
+			return Err(CursorError::SizeMismatch {
+				expected: size,
+				actual: bytes.len(),
+			});
+		}
+
+		self.uniforms_mut(target.uniform_offset, bytes.len())?
+			.copy_from_slice(bytes);
+		Ok(())
+	}
+
+	pub fn set_f32(&mut self, value: f32) -> Result<(), CursorError> {
+		self.set_scalars(
+			TypeKind::Scalar,
+			ScalarType::Float32,
+			&[value.to_ne_bytes()],
+		)
+	}
+
+	pub fn set_i32(&mut self, value: i32) -> Result<(), CursorError> {
+		self.set_scalars(TypeKind::Scalar, ScalarType::Int32, &[value.to_ne_bytes()])
+	}
+
+	pub fn set_u32(&mut self, value: u32) -> Result<(), CursorError> {
+		self.set_scalars(TypeKind::Scalar, ScalarType::Uint32, &[value.to_ne_bytes()])
+	}
+
+	pub fn set_bool(&mut self, value: bool) -> Result<(), CursorError> {
+		let value = (value as u32).to_ne_bytes();
+		self.set_scalars(TypeKind::Scalar, ScalarType::Bool, &[value])
+	}
+
+	pub fn set_f32x2(&mut self, value: [f32; 2]) -> Result<(), CursorError> {
+		self.set_vector(value)
+	}
+
+	pub fn set_f32x3(&mut self, value: [f32; 3]) -> Result<(), CursorError> {
+		self.set_vector(value)
+	}
+
+	pub fn set_f32x4(&mut self, value: [f32; 4]) -> Result<(), CursorError> {
+		self.set_vector(value)
+	}
+
+	/// Writes a float matrix given as rows, following the layout mode and row or column stride
+	/// of the shader type.
+	pub fn set_matrix<const ROWS: usize, const COLUMNS: usize>(
+		&mut self,
+		rows: &[[f32; COLUMNS]; ROWS],
+	) -> Result<(), CursorError> {
+		let target = self.target.clone()?;
+		let layout = target.layout;
+		let ty = layout.ty();
+
+		if layout.kind() != TypeKind::Matrix
+			|| ty.scalar_type() != ScalarType::Float32
+			|| ty.row_count() as usize != ROWS
+			|| ty.column_count() as usize != COLUMNS
+		{
+			return Err(CursorError::TypeMismatch);
+		}
+
+		let row_major = layout.matrix_layout_mode() == MatrixLayoutMode::RowMajor;
+		let (vectors, lanes) = if row_major {
+			(ROWS, COLUMNS)
+		} else {
+			(COLUMNS, ROWS)
+		};
+
+		// Rows or columns are aligned like the matrix, e.g. to 16 bytes in constant buffers.
+		let alignment = layout.alignment(ParameterCategory::Uniform).max(1) as usize;
+		let stride = (lanes * 4).next_multiple_of(alignment);
+
+		let uniforms =
+			self.uniforms_mut(target.uniform_offset, (vectors - 1) * stride + lanes * 4)?;
+
+		for (row, values) in rows.iter().enumerate() {
+			for (column, value) in values.iter().enumerate() {
+				let (vector, lane) = if row_major {
+					(row, column)
+				} else {
+					(column, row)
+				};
+
+				let offset = vector * stride + lane * 4;
+				uniforms[offset..offset + 4].copy_from_slice(&value.to_ne_bytes());
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Records `resource` in the binding table, replacing what was set at the same place before.
+	pub fn set_resource(&mut self, resource: R) -> Result<(), CursorError> {
+		let target = self.target.clone()?;
+		let root = self.data.layout;
+		let range = target.binding_range;
+
+		if target.layout.binding_range_count() == 0 || range >= root.binding_range_count() {
+			return Err(CursorError::NotAResource);
+		}
+
+		let descriptor_set = root.binding_range_descriptor_set_index(range);
+		let (space, index) = if descriptor_set < 0 {
+			(-1, -1)
+		} else {
+			let descriptor_range = root.binding_range_first_descriptor_range_index(range);
+
+			(
+				root.descriptor_set_space_offset(descriptor_set),
+				root.descriptor_set_descriptor_range_index_offset(descriptor_set, descriptor_range)
+					+ target.array_index as i64,
+			)
+		};
+
+		let binding = ResourceBinding {
+			resource,
+			binding_range: range,
+			array_index: target.array_index,
+			binding_type: root.binding_range_type(range),
+			descriptor_set,
+			space,
+			index,
+		};
+
+		let bindings = &mut self.data.bindings;
+
+		match bindings
+			.iter_mut()
+			.find(|b| (b.binding_range, b.array_index) == (range, target.array_index))
+		{
+			Some(existing) => *existing = binding,
+			None => bindings.push(binding),
+		}
+
+		Ok(())
+	}
+
+	/// The uniform data at `offset`, which can be past the end for elements of unsized arrays.
+	fn uniforms_mut(&mut self, offset: usize, len: usize) -> Result<&mut [u8], CursorError> {
+		let uniforms = &mut self.data.uniforms;
+		let error = CursorError::SizeMismatch {
+			expected: uniforms.len().saturating_sub(offset),
+			actual: len,
+		};
+
+		offset
+			.checked_add(len)
+			.and_then(|end| uniforms.get_mut(offset..end))
+			.ok_or(error)
+	}
+
+	fn set_vector<const N: usize>(&mut self, value: [f32; N]) -> Result<(), CursorError> {
+		self.set_scalars(
+			TypeKind::Vector,
+			ScalarType::Float32,
+			&value.map(f32::to_ne_bytes),
+		)
+	}
+
+	fn set_scalars(
+		&mut self,
+		kind: TypeKind,
+		scalar_type: ScalarType,
+		values: &[[u8; 4]],
+	) -> Result<(), CursorError> {
+		let layout = self.target.clone()?.layout;
+		let ty = layout.ty();
+
+		let count = match kind {
+			TypeKind::Vector => ty.element_count(),
+			_ => 1,
+		};
+
+		if layout.kind() != kind || ty.scalar_type() != scalar_type || count != values.len() {
+			return Err(CursorError::TypeMismatch);
+		}
+
+		self.set_bytes(values.concat().as_slice())
+	}
+}
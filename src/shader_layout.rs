@@ -0,0 +1,305 @@
+use std::fmt;
+
+use crate::reflection::TypeLayout;
+use crate::{MatrixLayoutMode, ParameterCategory, ScalarType, TypeKind};
+
+/// A Rust type whose memory layout can be checked against the uniform layout of a Slang type,
+/// see [`TypeLayout::check_compatible`].
+///
+/// Implemented for scalars and arrays, structs implement it with `#[derive(ShaderLayout)]` from
+/// the `derive` feature. Vectors and matrices are arrays of scalars.
+pub trait ShaderLayout {
+	/// The type of scalars, or of the scalars in vectors, matrices and arrays.
+	const SCALAR_TYPE: Option<ScalarType> = None;
+
+	/// The size of the elements of an array.
+	const ELEMENT_SIZE: Option<usize> = None;
+
+	/// The fields of a struct, or of the elements of an array of structs.
+	fn fields() -> Vec<FieldLayout> {
+		Vec::new()
+	}
+}
+
+macro_rules! scalar_layout {
+	($($ty:ty => $scalar:ident),*) => {
+		$(impl ShaderLayout for $ty {
+			const SCALAR_TYPE: Option<ScalarType> = Some(ScalarType::$scalar);
+		})*
+	};
+}
+
+scalar_layout!(
+	i8 => Int8, u8 => Uint8, i16 => Int16, u16 => Uint16, i32 => Int32, u32 => Uint32,
+	i64 => Int64, u64 => Uint64, f32 => Float32, f64 => Float64
+);
+
+impl<T: ShaderLayout, const N: usize> ShaderLayout for [T; N] {
+	const SCALAR_TYPE: Option<ScalarType> = T::SCALAR_TYPE;
+	const ELEMENT_SIZE: Option<usize> = Some(std::mem::size_of::<T>());
+
+	fn fields() -> Vec<FieldLayout> {
+		T::fields()
+	}
+}
+
+/// A field of a Rust struct, as recorded by `#[derive(ShaderLayout)]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldLayout {
+	/// The name of the matching shader field.
+	pub name: &'static str,
+	pub offset: usize,
+	pub size: usize,
+	/// The size of the elements for arrays, checked against the shader's element stride.
+	pub element_size: Option<usize>,
+	pub scalar_type: Option<ScalarType>,
+	/// Only checked when set.
+	pub matrix_layout_mode: Option<MatrixLayoutMode>,
+	pub fields: Vec<FieldLayout>,
+}
+
+/// A difference between a Rust type and a Slang type layout. Paths are the dotted field names,
+/// empty for the type itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LayoutMismatch {
+	/// The shader has a field the Rust type doesn't.
+	MissingField { path: String },
+	/// The Rust type has a field the shader doesn't.
+	UnknownField { path: String },
+	Offset {
+		path: String,
+		shader: usize,
+		rust: usize,
+	},
+	Size {
+		path: String,
+		shader: usize,
+		rust: usize,
+	},
+	/// The elements of an array are further apart in the shader than in Rust, or the other way
+	/// around, e.g. `[f32; 12]` for a `float[3]` in a constant buffer.
+	ElementStride {
+		path: String,
+		shader: usize,
+		rust: usize,
+	},
+	ScalarType {
+		path: String,
+		shader: ScalarType,
+		rust: Option<ScalarType>,
+	},
+	MatrixLayoutMode {
+		path: String,
+		shader: MatrixLayoutMode,
+		rust: MatrixLayoutMode,
+	},
+}
+
+impl fmt::Display for LayoutMismatch {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::MissingField { path } => write!(f, "`{path}` is missing in the Rust type"),
+			Self::UnknownField { path } => write!(f, "`{path}` is not in the shader"),
+			Self::Offset { path, shader, rust } => {
+				write!(
+					f,
+					"`{path}` is at offset {shader} in the shader, {rust} in Rust"
+				)
+			}
+			Self::Size { path, shader, rust } if path.is_empty() => {
+				write!(
+					f,
+					"the type is {shader} bytes in the shader, {rust} in Rust"
+				)
+			}
+			Self::Size { path, shader, rust } => {
+				write!(
+					f,
+					"`{path}` is {shader} bytes in the shader, {rust} in Rust"
+				)
+			}
+			Self::ElementStride { path, shader, rust } => {
+				write!(
+					f,
+					"`{path}` has a {shader} byte element stride in the shader, {rust} in Rust"
+				)
+			}
+			Self::ScalarType { path, shader, rust } => {
+				write!(f, "`{path}` is {shader:?} in the shader, {rust:?} in Rust")
+			}
+			Self::MatrixLayoutMode { path, shader, rust } => {
+				write!(f, "`{path}` is {shader:?} in the shader, {rust:?} in Rust")
+			}
+		}
+	}
+}
+
+impl TypeLayout<'_> {
+	/// Compares the layout of `T` with the uniform data of this type. Constant buffers, parameter
+	/// blocks and structured buffers are compared by their contents.
+	pub fn check_compatible<T: ShaderLayout>(self) -> Result<(), Vec<LayoutMismatch>> {
+		let layout = match self.kind() {
+			TypeKind::ConstantBuffer
+			| TypeKind::ParameterBlock
+			| TypeKind::ShaderStorageBuffer
+			| TypeKind::Resource => self.element_type_layout().unwrap_or(self),
+			_ => self,
+		};
+
+		let rust = FieldLayout {
+			name: "",
+			offset: 0,
+			size: std::mem::size_of::<T>(),
+			element_size: T::ELEMENT_SIZE,
+			scalar_type: T::SCALAR_TYPE,
+			matrix_layout_mode: None,
+			fields: T::fields(),
+		};
+
+		let mut mismatches = Vec::new();
+		check_field("", layout, &rust, &mut mismatches);
+
+		if mismatches.is_empty() {
+			Ok(())
+		} else {
+			Err(mismatches)
+		}
+	}
+}
+
+fn check_field(
+	path: &str,
+	layout: TypeLayout<'_>,
+	rust: &FieldLayout,
+	mismatches: &mut Vec<LayoutMismatch>,
+) {
+	// Structs and arrays can end in padding that Slang only counts in the stride.
+	let size = layout.size(ParameterCategory::Uniform);
+	let stride = layout.stride(ParameterCategory::Uniform);
+
+	if rust.size < size || rust.size > size.max(stride) {
+		mismatches.push(LayoutMismatch::Size {
+			path: path.to_string(),
+			shader: size,
+			rust: rust.size,
+		});
+	}
+
+	// Without padding after the last element, as in D3D constant buffers, the Rust type can't be
+	// an array of the elements and only its size is checked.
+	if let (TypeKind::Array, Some(rust_stride)) = (layout.kind(), rust.element_size) {
+		let shader_stride = layout.element_stride(ParameterCategory::Uniform);
+		let padded = size == layout.element_count().saturating_mul(shader_stride);
+
+		if padded && rust_stride != shader_stride {
+			mismatches.push(LayoutMismatch::ElementStride {
+				path: path.to_string(),
+				shader: shader_stride,
+				rust: rust_stride,
+			});
+		}
+	}
+
+	// Arrays are compared by their elements.
+	let mut element = layout;
+	while let (TypeKind::Array, Some(inner)) = (element.kind(), element.element_type_layout()) {
+		element = inner;
+	}
+
+	match element.kind() {
+		TypeKind::Scalar | TypeKind::Vector | TypeKind::Matrix => {
+			let shader = element.ty().scalar_type();
+
+			if !scalar_compatible(shader, rust.scalar_type) {
+				mismatches.push(LayoutMismatch::ScalarType {
+					path: path.to_string(),
+					shader,
+					rust: rust.scalar_type,
+				});
+			}
+
+			let shader = element.matrix_layout_mode();
+
+			match rust.matrix_layout_mode {
+				Some(rust) if element.kind() == TypeKind::Matrix && rust != shader => {
+					mismatches.push(LayoutMismatch::MatrixLayoutMode {
+						path: path.to_string(),
+						shader,
+						rust,
+					});
+				}
+				_ => {}
+			}
+		}
+		TypeKind::Struct => check_fields(path, element, &rust.fields, mismatches),
+		_ => {}
+	}
+}
+
+fn check_fields(
+	path: &str,
+	layout: TypeLayout<'_>,
+	rust_fields: &[FieldLayout],
+	mismatches: &mut Vec<LayoutMismatch>,
+) {
+	let mut matched = vec![false; rust_fields.len()];
+
+	for field in layout.fields() {
+		let field_layout = field.type_layout();
+
+		// Resources and samplers have no uniform data.
+		if field_layout.size(ParameterCategory::Uniform) == 0 {
+			continue;
+		}
+
+		let name = field.variable().and_then(|v| v.name()).unwrap_or_default();
+		let path = field_path(path, name);
+
+		let Some(index) = rust_fields.iter().position(|rust| rust.name == name) else {
+			mismatches.push(LayoutMismatch::MissingField { path });
+			continue;
+		};
+
+		matched[index] = true;
+		let rust = &rust_fields[index];
+		let offset = field.offset(ParameterCategory::Uniform);
+
+		if rust.offset != offset {
+			mismatches.push(LayoutMismatch::Offset {
+				path: path.clone(),
+				shader: offset,
+				rust: rust.offset,
+			});
+		}
+
+		check_field(&path, field_layout, rust, mismatches);
+	}
+
+	for (rust, _) in rust_fields
+		.iter()
+		.zip(matched)
+		.filter(|(_, matched)| !matched)
+	{
+		mismatches.push(LayoutMismatch::UnknownField {
+			path: field_path(path, rust.name),
+		});
+	}
+}
+
+fn scalar_compatible(shader: ScalarType, rust: Option<ScalarType>) -> bool {
+	match (shader, rust) {
+		(shader, Some(rust)) if shader == rust => true,
+		// Booleans are 32 bits in buffers, halfs are stored as their bits.
+		(ScalarType::Bool, Some(ScalarType::Uint32 | ScalarType::Int32)) => true,
+		(ScalarType::Float16, Some(ScalarType::Uint16)) => true,
+		_ => false,
+	}
+}
+
+fn field_path(parent: &str, name: &str) -> String {
+	if parent.is_empty() {
+		name.to_string()
+	} else {
+		format!("{parent}.{name}")
+	}
+}
@@ -0,0 +1,106 @@
+use std::ffi::{c_void, CString};
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use crate::{sys, uuid, IUnknown, Interface, UUID};
+
+/// Native code compiled for a `ShaderHostCallable` or `HostHostCallable` target, see
+/// [`crate::ComponentType::entry_point_host_callable`].
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct SharedLibrary(pub(crate) IUnknown);
+
+unsafe impl Interface for SharedLibrary {
+	type Vtable = sys::ISharedLibraryVtable;
+	const IID: UUID = uuid(
+		0x70dbc7c4,
+		0xdc3b,
+		0x4a07,
+		[0xae, 0x7e, 0x75, 0x2a, 0xf6, 0xa8, 0x15, 0x55],
+	);
+}
+
+impl SharedLibrary {
+	/// `None` if there's no such symbol, or if `name` contains a NUL byte.
+	pub fn find_symbol_address_by_name(&self, name: &str) -> Option<NonNull<c_void>> {
+		let name = CString::new(name).ok()?;
+		let address =
+			unsafe { (self.vtable().findSymbolAddressByName)(self.as_raw(), name.as_ptr()) };
+		NonNull::new(address)
+	}
+
+	/// The function has to be transmuted to its actual signature before it's called.
+	pub fn find_func_by_name(&self, name: &str) -> Option<unsafe extern "C" fn()> {
+		self.find_symbol_address_by_name(name)
+			.map(|address| unsafe { std::mem::transmute(address.as_ptr()) })
+	}
+
+	/// Looks up a compute entry point, which is exported under its name.
+	pub fn find_compute_kernel(&self, name: &str) -> Option<ComputeKernel<'_>> {
+		self.find_symbol_address_by_name(name)
+			.map(|address| ComputeKernel {
+				func: unsafe { std::mem::transmute::<*mut c_void, ComputeFunc>(address.as_ptr()) },
+				_library: PhantomData,
+			})
+	}
+}
+
+/// The range of thread groups a compute kernel runs, `end_group_id` is exclusive.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComputeVaryingInput {
+	pub start_group_id: [u32; 3],
+	pub end_group_id: [u32; 3],
+}
+
+/// `void main(ComputeVaryingInput*, void* entryPointParams, void* globalParams)`.
+type ComputeFunc = unsafe extern "C" fn(*mut ComputeVaryingInput, *mut c_void, *mut c_void);
+
+/// A compute entry point compiled to native code, borrowing the library it was found in.
+#[derive(Clone, Copy)]
+pub struct ComputeKernel<'a> {
+	func: ComputeFunc,
+	_library: PhantomData<&'a SharedLibrary>,
+}
+
+impl ComputeKernel<'_> {
+	/// Runs `group_count` thread groups, one after the other on the calling thread.
+	///
+	/// # Safety
+	///
+	/// `E` and `G` must have the layout of the entry point parameters and the global parameters for
+	/// the CPU target, e.g. structured buffers are a pointer followed by a `usize` element count.
+	/// Buffers must be large enough for every access the kernel makes.
+	pub unsafe fn dispatch<E, G>(
+		&self,
+		group_count: [u32; 3],
+		entry_point_params: &mut E,
+		global_params: &mut G,
+	) {
+		let varying_input = ComputeVaryingInput {
+			start_group_id: [0; 3],
+			end_group_id: group_count,
+		};
+
+		self.dispatch_range(varying_input, entry_point_params, global_params);
+	}
+
+	/// Same as [`ComputeKernel::dispatch`] for a range of thread groups, e.g. to split a dispatch
+	/// between threads.
+	///
+	/// # Safety
+	///
+	/// See [`ComputeKernel::dispatch`].
+	pub unsafe fn dispatch_range<E, G>(
+		&self,
+		mut varying_input: ComputeVaryingInput,
+		entry_point_params: &mut E,
+		global_params: &mut G,
+	) {
+		(self.func)(
+			&mut varying_input,
+			(entry_point_params as *mut E).cast(),
+			(global_params as *mut G).cast(),
+		);
+	}
+}
@@ -1,6 +1,23 @@
 use crate as slang;
 use slang::Downcast;
 
+fn create_session(global_session: &slang::GlobalSession) -> slang::Session {
+	let search_path = std::ffi::CString::new("shaders").unwrap();
+
+	let target_desc = slang::TargetDesc::default()
+		.format(slang::CompileTarget::Spirv)
+		.profile(global_session.find_profile("glsl_450"));
+
+	let targets = [target_desc];
+	let search_paths = [search_path.as_ptr()];
+
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.search_paths(&search_paths);
+
+	global_session.create_session(&session_desc).unwrap()
+}
+
 #[test]
 fn compile() {
 	let global_session = slang::GlobalSession::new().unwrap();
@@ -45,3 +62,1430 @@ fn compile() {
 	let shader_bytecode = linked_program.entry_point_code(0, 0).unwrap();
 	assert_ne!(shader_bytecode.as_slice().len(), 0);
 }
+
+#[test]
+fn load_module_from_source() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = global_session
+		.create_session(&slang::SessionDesc::default())
+		.unwrap();
+
+	let source = include_str!("../shaders/test.slang");
+	let module = session
+		.load_module_from_source("generated", "generated.slang", source)
+		.unwrap();
+	assert!(module.find_entry_point_by_name("main").is_some());
+
+	let result = session.load_module_from_source("broken", "broken.slang", "void main( {");
+	assert!(result.is_err());
+
+	let result = session.load_module_from_source("nul", "nul.slang", "void main() {}\0");
+	assert!(matches!(result, Err(slang::Error::Code(result)) if result == slang::E_INVALID_ARG));
+}
+
+#[test]
+fn serialize_module() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = global_session
+		.create_session(&slang::SessionDesc::default())
+		.unwrap();
+
+	let source = include_str!("../shaders/test.slang");
+	let module = session
+		.load_module_from_source("serialized", "serialized.slang", source)
+		.unwrap();
+
+	let ir = module.serialize().unwrap();
+	assert_ne!(ir.as_slice().len(), 0);
+
+	let session = global_session
+		.create_session(&slang::SessionDesc::default())
+		.unwrap();
+	let module = session
+		.load_module_from_ir_blob("serialized", "serialized.slang-module", &ir)
+		.unwrap();
+	assert!(module.find_entry_point_by_name("main").is_some());
+}
+
+#[test]
+fn specialize() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("generic.slang").unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+	assert_eq!(program.specialization_param_count(), 1);
+
+	let ty = program
+		.layout(0)
+		.unwrap()
+		.find_type_by_name("Multiply")
+		.unwrap();
+	let specialized = program
+		.specialize(&[slang::SpecializationArg::from_type(ty)])
+		.unwrap();
+	assert_eq!(specialized.specialization_param_count(), 0);
+
+	let linked_program = specialized.link().unwrap();
+	let shader_bytecode = linked_program.entry_point_code(0, 0).unwrap();
+	assert_ne!(shader_bytecode.as_slice().len(), 0);
+}
+
+#[test]
+fn type_conformance() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("generic.slang").unwrap();
+	let reflection = module.downcast().layout(0).unwrap();
+
+	let ty = reflection.find_type_by_name("Multiply").unwrap();
+	let interface = reflection.find_type_by_name("IOperation").unwrap();
+
+	let conformance = session
+		.create_type_conformance_component_type(ty, interface, None)
+		.unwrap();
+	let id = session
+		.type_conformance_witness_sequential_id(ty, interface)
+		.unwrap();
+	assert_eq!(id, 0);
+
+	let name = session
+		.type_conformance_witness_mangled_name(ty, interface)
+		.unwrap();
+	assert_ne!(name.as_slice().len(), 0);
+
+	session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			conformance.downcast().clone(),
+		])
+		.unwrap();
+}
+
+#[test]
+fn session_type_layout() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("test.slang").unwrap();
+	let reflection = module.downcast().layout(0).unwrap();
+	let float = reflection.find_type_by_name("float").unwrap();
+
+	let buffer = session
+		.container_type(float, slang::ContainerType::StructuredBuffer)
+		.unwrap()
+		.into_value();
+	let layout = session
+		.type_layout(buffer, 0, slang::LayoutRules::Default)
+		.unwrap();
+
+	let element_layout = layout.element_type_layout().unwrap();
+	assert_eq!(element_layout.size(slang::ParameterCategory::Uniform), 4);
+}
+
+#[test]
+fn target_code() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("test.slang").unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+
+	let options = slang::CompilerOptions::default()
+		.optimization(slang::OptimizationLevel::None)
+		.debug_information(slang::DebugInfoLevel::Maximal);
+	let linked_program = program.link_with_options(&options).unwrap();
+
+	let spirv = linked_program.target_code(0).unwrap();
+	assert_ne!(spirv.as_slice().len(), 0);
+}
+
+#[test]
+fn find_and_check_entry_point() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let source = r#"
+		RWStructuredBuffer<float> output;
+
+		[numthreads(1, 1, 1)]
+		void fill(uint3 thread_id : SV_DispatchThreadID) {
+			output[thread_id.x] = 1.0;
+		}
+	"#;
+
+	let module = session
+		.load_module_from_source("unchecked", "unchecked.slang", source)
+		.unwrap();
+	assert!(module.find_entry_point_by_name("fill").is_none());
+
+	let entry_point = module
+		.find_and_check_entry_point("fill", slang::Stage::Compute)
+		.unwrap();
+	let renamed = entry_point
+		.downcast()
+		.rename_entry_point("fill_ones")
+		.unwrap();
+
+	let program = session
+		.create_composite_component_type(&[module.downcast().clone(), renamed])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+
+	let shader_bytecode = linked_program.entry_point_code(0, 0).unwrap();
+	assert_ne!(shader_bytecode.as_slice().len(), 0);
+}
+
+#[test]
+fn dependency_file_paths() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("dependencies.slang").unwrap();
+	let paths = module.dependency_file_paths().collect::<Vec<_>>();
+	assert!(paths
+		.iter()
+		.any(|path| path.ends_with("dependencies.slang")));
+	assert!(paths.iter().any(|path| path.ends_with("generic.slang")));
+	assert!(paths.iter().any(|path| path.ends_with("scale.slangh")));
+}
+
+#[cfg(feature = "hot-reload")]
+#[test]
+fn hot_reload() {
+	use std::cell::Cell;
+	use std::rc::Rc;
+	use std::time::{Duration, SystemTime};
+
+	// Test runs can overlap, each gets a directory of its own.
+	let nanos = SystemTime::now()
+		.duration_since(SystemTime::UNIX_EPOCH)
+		.unwrap()
+		.as_nanos();
+	let dir = std::env::temp_dir().join(format!(
+		"slang-rs-hot-reload-{}-{nanos}",
+		std::process::id()
+	));
+	std::fs::create_dir_all(&dir).unwrap();
+
+	let path = dir.join("watched.slang");
+	let source = include_str!("../shaders/test.slang");
+	std::fs::write(&path, source).unwrap();
+
+	let global_session = slang::GlobalSession::new().unwrap();
+	let search_path = std::ffi::CString::new(dir.to_str().unwrap()).unwrap();
+
+	let mut watcher = slang::hot_reload::ShaderWatcher::new(move || {
+		let targets = [slang::TargetDesc::default().format(slang::CompileTarget::Spirv)];
+		let search_paths = [search_path.as_ptr()];
+
+		let session_desc = slang::SessionDesc::default()
+			.targets(&targets)
+			.search_paths(&search_paths);
+
+		global_session.create_session(&session_desc)
+	})
+	.unwrap();
+
+	let reloads = Rc::new(Cell::new(0));
+	let failures = Rc::new(Cell::new(0));
+
+	let program = watcher
+		.watch("watched.slang", &["main"], 0, {
+			let reloads = reloads.clone();
+			let failures = failures.clone();
+			move |result| match result {
+				Ok(_) => reloads.set(reloads.get() + 1),
+				Err(_) => failures.set(failures.get() + 1),
+			}
+		})
+		.unwrap();
+	assert_eq!(program.code.len(), 1);
+	assert_eq!(watcher.poll(), 0);
+
+	assert!(watcher
+		.watch("missing.slang", &["main"], 0, |_| {})
+		.is_err());
+	assert!(matches!(
+		watcher.watch("watched.slang", &["missing"], 0, |_| {}),
+		Err(slang::Error::EntryPointNotFound(name)) if name == "missing"
+	));
+
+	// Bump the modification time explicitly, file systems can have a coarse timestamp resolution.
+	let touch = |contents: &str, offset: u64| {
+		std::fs::write(&path, contents).unwrap();
+		let file = std::fs::File::options().write(true).open(&path).unwrap();
+		file.set_modified(SystemTime::now() + Duration::from_secs(offset))
+			.unwrap();
+	};
+
+	touch(&source.replace("*", "+"), 1);
+	assert_eq!(watcher.poll(), 1);
+	assert_eq!(reloads.get(), 1);
+
+	touch("void main( {", 2);
+	assert_eq!(watcher.poll(), 1);
+	assert_eq!(failures.get(), 1);
+
+	// The broken file isn't rebuilt until it changes again.
+	assert_eq!(watcher.poll(), 0);
+
+	touch(source, 3);
+	assert_eq!(watcher.poll(), 1);
+	assert_eq!(reloads.get(), 2);
+
+	drop(watcher);
+	std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn parse_diagnostics() {
+	let output = "\
+shaders/test.slang(9): error 30015: undefined identifier 'inputs'.
+	output[index] = inputs[index];
+	                ^~~~~~
+C:\\Program Files (x86)\\shader.slang(3): warning 15205: implicit conversion.
+error 1: cannot open file 'missing.slang'.
+";
+
+	let diagnostics = slang::Diagnostic::parse(output);
+	assert_eq!(diagnostics.len(), 3);
+
+	assert_eq!(
+		diagnostics[0],
+		slang::Diagnostic {
+			severity: slang::Severity::Error,
+			code: Some(30015),
+			file: Some("shaders/test.slang".to_string()),
+			line: Some(9),
+			column: Some(18),
+			message: "undefined identifier 'inputs'.".to_string(),
+		}
+	);
+
+	assert_eq!(diagnostics[1].severity, slang::Severity::Warning);
+	assert_eq!(
+		diagnostics[1].file.as_deref(),
+		Some("C:\\Program Files (x86)\\shader.slang")
+	);
+	assert_eq!(diagnostics[1].line, Some(3));
+
+	assert_eq!(diagnostics[2].code, Some(1));
+	assert_eq!(diagnostics[2].file, None);
+}
+
+#[test]
+fn compile_error_diagnostics() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let Err(slang::Error::Diagnostics(diagnostics)) =
+		session.load_module_from_source("broken", "broken.slang", "void main( {")
+	else {
+		panic!("expected a compile error");
+	};
+
+	let error = diagnostics.errors().next().unwrap();
+	assert_eq!(error.file.as_deref(), Some("broken.slang"));
+	assert_eq!(error.line, Some(1));
+}
+
+#[test]
+fn compile_warnings() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let source = r#"
+		RWStructuredBuffer<float> output;
+
+		[shader("compute")]
+		[numthreads(1, 1, 1)]
+		void main(uint3 thread_id : SV_DispatchThreadID) {
+			float truncated = float3(1.0, 2.0, 3.0);
+			output[thread_id.x] = truncated;
+		}
+	"#;
+
+	let module = session
+		.load_module_from_source("warnings", "warnings.slang", source)
+		.unwrap();
+
+	let warning = module.warnings().next().unwrap();
+	assert_eq!(warning.file.as_deref(), Some("warnings.slang"));
+}
+
+#[test]
+fn custom_file_system() {
+	use std::collections::HashMap;
+
+	struct MemoryFileSystem(HashMap<&'static str, &'static str>);
+
+	impl slang::FileSystem for MemoryFileSystem {
+		fn load_file(&self, path: &str) -> std::io::Result<Vec<u8>> {
+			self.0
+				.get(path)
+				.map(|source| source.as_bytes().to_vec())
+				.ok_or_else(|| std::io::ErrorKind::NotFound.into())
+		}
+	}
+
+	let file_system = slang::CustomFileSystem::new(MemoryFileSystem(HashMap::from([
+		(
+			"memory/lib.slang",
+			"float scale(float x) { return x * 2.0; }",
+		),
+		(
+			"memory/main.slang",
+			r#"
+				import lib;
+
+				RWStructuredBuffer<float> output;
+
+				[shader("compute")]
+				[numthreads(1, 1, 1)]
+				void main(uint3 thread_id : SV_DispatchThreadID) {
+					output[thread_id.x] = scale(1.0);
+				}
+			"#,
+		),
+	])));
+
+	let global_session = slang::GlobalSession::new().unwrap();
+	let search_path = std::ffi::CString::new("memory").unwrap();
+
+	let targets = [slang::TargetDesc::default()
+		.format(slang::CompileTarget::Spirv)
+		.profile(global_session.find_profile("glsl_450"))];
+	let search_paths = [search_path.as_ptr()];
+
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.search_paths(&search_paths)
+		.file_system(&file_system);
+
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	// The session holds its own reference.
+	drop(file_system);
+
+	let module = session.load_module("main").unwrap();
+	assert!(module.find_entry_point_by_name("main").is_some());
+	assert_eq!(module.dependency_file_count(), 2);
+}
+
+#[test]
+fn rust_blob() {
+	let blob = slang::Blob::from(String::from("hello"));
+	let copy = blob.clone();
+	drop(blob);
+	assert_eq!(copy.as_str().unwrap(), "hello");
+	assert_eq!(slang::Blob::from_vec(Vec::new()).as_slice(), &[] as &[u8]);
+
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = global_session
+		.create_session(&slang::SessionDesc::default())
+		.unwrap();
+
+	// Slang keeps its own reference to the source while the module is alive.
+	let source = slang::Blob::from(include_str!("../shaders/test.slang"));
+	let module = session
+		.load_module_from_source_blob("rust_blob", "rust_blob.slang", &source)
+		.unwrap();
+	drop(source);
+	assert!(module.find_entry_point_by_name("main").is_some());
+
+	// Round-trip the serialized module through a blob owned by Rust.
+	let ir = slang::Blob::from_vec(module.serialize().unwrap().as_slice().to_vec());
+
+	let session = global_session
+		.create_session(&slang::SessionDesc::default())
+		.unwrap();
+	let module = session
+		.load_module_from_ir_blob("rust_blob", "rust_blob.slang-module", &ir)
+		.unwrap();
+	drop(ir);
+	assert!(module.find_entry_point_by_name("main").is_some());
+}
+
+#[test]
+fn reflection_lifetimes() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("test.slang").unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+
+	// Handles borrow the program, not the handle they were reached through.
+	let types = {
+		let reflection = linked_program.layout(0).unwrap().into_value();
+		reflection
+			.parameters()
+			.map(|parameter| parameter.ty().unwrap())
+			.collect::<Vec<_>>()
+	};
+
+	assert_eq!(types.len(), 3);
+	assert!(types.iter().all(|ty| ty.name().is_some()));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn program_reflection_snapshot() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("test.slang").unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+
+	let snapshot = slang::reflection::ProgramReflection::from(*linked_program.layout(0).unwrap());
+	drop(linked_program);
+
+	assert_eq!(snapshot.parameters.len(), 3);
+	assert_eq!(snapshot.entry_points[0].name, "main");
+	assert_eq!(
+		snapshot.entry_points[0].stage,
+		slang::slang_reflection::Stage::Compute
+	);
+
+	let json = serde_json::to_string(&snapshot).unwrap();
+	let loaded: slang::reflection::ProgramReflection = serde_json::from_str(&json).unwrap();
+	assert_eq!(loaded, snapshot);
+}
+
+#[test]
+fn reflection_json() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("reflection.slang").unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+
+	let json = linked_program.layout(0).unwrap().to_json();
+
+	// Compared as values, whitespace differences between Slang versions don't matter.
+	let expected = std::fs::read_to_string("shaders/reflection.json").unwrap();
+	let actual: serde_json::Value = serde_json::from_str(&json).unwrap();
+	let expected: serde_json::Value = serde_json::from_str(&expected).unwrap();
+	assert_eq!(actual, expected);
+}
+
+#[test]
+fn rust_struct_codegen() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("uniforms.slang").unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+
+	let reflection = linked_program.layout(0).unwrap();
+	let code = slang::codegen::RustStructs::default()
+		.program(*reflection)
+		.generate();
+
+	// `Light` is laid out the same in the constant and the structured buffer, so it's only
+	// generated once. Constant buffer arrays have a 16 byte stride.
+	assert_eq!(code, include_str!("../shaders/uniforms.rs"));
+}
+
+/// The output of `rust_struct_codegen`, included so the generated code is compiled and its layout
+/// assertions are checked.
+#[allow(dead_code)]
+mod uniforms {
+	include!("../shaders/uniforms.rs");
+}
+
+#[test]
+fn rust_struct_codegen_d3d_packing() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let search_path = std::ffi::CString::new("shaders").unwrap();
+	let targets = [slang::TargetDesc::default()
+		.format(slang::CompileTarget::Hlsl)
+		.profile(global_session.find_profile("sm_5_0"))];
+	let search_paths = [search_path.as_ptr()];
+
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.search_paths(&search_paths);
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let module = session.load_module("packing.slang").unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+
+	let reflection = linked_program.layout(0).unwrap();
+	let code = slang::codegen::RustStructs::default()
+		.program(*reflection)
+		.generate();
+
+	// The last column of `rotation` and the last element of `weights` aren't padded, `after` is
+	// packed into the rest of the last element.
+	assert_eq!(code, include_str!("../shaders/packing.rs"));
+}
+
+#[allow(dead_code)]
+mod packing {
+	include!("../shaders/packing.rs");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn check_shader_layout() {
+	#[repr(C)]
+	#[derive(slang::ShaderLayout)]
+	struct Light {
+		position: [f32; 3],
+		intensity: f32,
+		color: [f32; 4],
+	}
+
+	#[repr(C)]
+	#[derive(slang::ShaderLayout)]
+	struct Params {
+		#[shader(column_major)]
+		view: [[f32; 4]; 4],
+		lights: [Light; 2],
+		weights: [[f32; 4]; 3],
+		time: f32,
+		count: u32,
+		_pad: [u8; 8],
+	}
+
+	#[repr(C)]
+	#[derive(slang::ShaderLayout)]
+	struct UnpaddedParams {
+		#[shader(column_major)]
+		view: [[f32; 4]; 4],
+		lights: [Light; 2],
+		weights: [f32; 12],
+		time: f32,
+		count: u32,
+		_pad: [u8; 8],
+	}
+
+	#[repr(C)]
+	#[derive(slang::ShaderLayout)]
+	struct OutdatedParams {
+		view: [[f32; 4]; 4],
+		time: u32,
+		scale: f32,
+	}
+
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("uniforms.slang").unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+
+	let reflection = linked_program.layout(0).unwrap();
+	let params = reflection.parameter_by_index(0).unwrap().type_layout();
+	let lights = reflection.parameter_by_index(1).unwrap().type_layout();
+
+	assert_eq!(params.check_compatible::<Params>(), Ok(()));
+	assert_eq!(lights.check_compatible::<Light>(), Ok(()));
+
+	// The size matches, but the elements of `weights` are 16 bytes apart in the shader.
+	assert_eq!(
+		params.check_compatible::<UnpaddedParams>(),
+		Err(vec![slang::LayoutMismatch::ElementStride {
+			path: "weights".to_string(),
+			shader: 16,
+			rust: 4,
+		}])
+	);
+
+	let mismatches = params.check_compatible::<OutdatedParams>().unwrap_err();
+	assert!(mismatches.contains(&slang::LayoutMismatch::MissingField {
+		path: "lights".to_string()
+	}));
+	assert!(mismatches.contains(&slang::LayoutMismatch::UnknownField {
+		path: "scale".to_string()
+	}));
+	assert!(mismatches.contains(&slang::LayoutMismatch::Offset {
+		path: "time".to_string(),
+		shader: 176,
+		rust: 64,
+	}));
+	assert!(mismatches.contains(&slang::LayoutMismatch::ScalarType {
+		path: "time".to_string(),
+		shader: slang::ScalarType::Float32,
+		rust: Some(slang::ScalarType::Uint32),
+	}));
+}
+
+#[test]
+fn shader_cursor() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("uniforms.slang").unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+	let reflection = linked_program.layout(0).unwrap();
+
+	let params = reflection.parameter_by_index(0).unwrap().type_layout();
+	let mut data = slang::ShaderData::<()>::new(params);
+
+	data.cursor().field("time").set_f32(2.5).unwrap();
+	data.cursor()
+		.field("weights")
+		.index(2)
+		.set_f32(0.5)
+		.unwrap();
+	data.cursor()
+		.path("lights[1].color")
+		.set_f32x4([1.0, 2.0, 3.0, 4.0])
+		.unwrap();
+
+	let identity = [
+		[1.0, 0.0, 0.0, 0.0],
+		[0.0, 1.0, 0.0, 0.0],
+		[0.0, 0.0, 1.0, 0.0],
+		[5.0, 6.0, 7.0, 1.0],
+	];
+	data.cursor().field("view").set_matrix(&identity).unwrap();
+
+	let read =
+		|offset: usize| f32::from_ne_bytes(data.uniforms()[offset..offset + 4].try_into().unwrap());
+	assert_eq!(read(176), 2.5);
+	// Constant buffer arrays have a 16 byte stride.
+	assert_eq!(read(128 + 2 * 16), 0.5);
+	assert_eq!(read(64 + 32 + 16 + 8), 3.0);
+	// Matrices are column major by default, the translation ends up in the last lane of each column.
+	assert_eq!(read(12), 5.0);
+
+	assert_eq!(
+		data.cursor().field("missing").set_f32(1.0),
+		Err(slang::CursorError::NoSuchField("missing".to_string()))
+	);
+	assert_eq!(
+		data.cursor().field("count").set_f32(1.0),
+		Err(slang::CursorError::TypeMismatch)
+	);
+
+	let globals = reflection.global_params_type_layout();
+	let mut bindings = slang::ShaderData::new(globals);
+	bindings
+		.cursor()
+		.field("all_lights")
+		.set_resource("lights")
+		.unwrap();
+	bindings
+		.cursor()
+		.field("output")
+		.set_resource("output")
+		.unwrap();
+
+	let all_lights = &bindings.bindings()[0];
+	assert_eq!(all_lights.resource, "lights");
+	assert_eq!(all_lights.binding_type, slang::BindingType::RawBuffer);
+	assert_eq!((all_lights.space, all_lights.index), (0, 1));
+	assert_eq!(bindings.bindings()[1].index, 2);
+}
+
+#[test]
+fn descriptor_layout() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("test.slang").unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+	let reflection = linked_program.layout(0).unwrap();
+
+	let layout = slang::reflection::DescriptorLayout::from_program(*reflection).unwrap();
+	assert!(layout.push_constant_ranges.is_empty());
+	assert_eq!(layout.sets.len(), 1);
+
+	let set = &layout.sets[0];
+	assert_eq!(set.space, 0);
+
+	let bindings = set
+		.bindings
+		.iter()
+		.map(|binding| (binding.binding, binding.binding_type, binding.count))
+		.collect::<Vec<_>>();
+
+	assert_eq!(
+		bindings,
+		[
+			(0, slang::BindingType::RawBuffer, 1),
+			(1, slang::BindingType::RawBuffer, 1),
+			(2, slang::BindingType::MutableRawBuffer, 1),
+		]
+	);
+
+	let stages = set.bindings[0].stages;
+	assert!(stages.contains(slang::Stage::Compute));
+	assert_eq!(stages.iter().count(), 1);
+}
+
+#[test]
+fn descriptor_layout_push_constants() {
+	use slang::reflection::{DescriptorLayout, StageFlags};
+
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("descriptors.slang").unwrap();
+	let vertex = module.find_entry_point_by_name("vertex_main").unwrap();
+	let fragment = module.find_entry_point_by_name("fragment_main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			vertex.downcast().clone(),
+			fragment.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+	let reflection = linked_program.layout(0).unwrap();
+
+	let layout = DescriptorLayout::from_program(*reflection).unwrap();
+
+	let ranges = layout
+		.push_constant_ranges
+		.iter()
+		.map(|range| (range.offset, range.size, range.stages))
+		.collect::<Vec<_>>();
+
+	// The vertex stage sees both the global `float4` and its own `float4x4`, a stage can only be
+	// in one range.
+	assert_eq!(
+		ranges,
+		[
+			(0, 64, StageFlags::from(slang::Stage::Vertex)),
+			(0, 16, StageFlags::from(slang::Stage::Fragment)),
+		]
+	);
+}
+
+#[test]
+fn descriptor_layout_blocks() {
+	use slang::reflection::{DescriptorLayout, StageFlags};
+
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("descriptors.slang").unwrap();
+	let vertex = module.find_entry_point_by_name("vertex_main").unwrap();
+	let fragment = module.find_entry_point_by_name("fragment_main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			vertex.downcast().clone(),
+			fragment.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+	let reflection = linked_program.layout(0).unwrap();
+
+	let layout = DescriptorLayout::from_program(*reflection).unwrap();
+
+	let sets = layout
+		.sets
+		.iter()
+		.map(|set| {
+			let bindings = set
+				.bindings
+				.iter()
+				.map(|binding| (binding.binding, binding.binding_type, binding.count))
+				.collect::<Vec<_>>();
+
+			(set.space, bindings)
+		})
+		.collect::<Vec<_>>();
+
+	// Global resources and those of entry points share set 0, the parameter block gets its own
+	// set with a buffer for its uniforms.
+	assert_eq!(
+		sets,
+		[
+			(
+				0,
+				vec![
+					(0, slang::BindingType::MutableTeture, 1),
+					(1, slang::BindingType::Texture, 1),
+				]
+			),
+			(
+				1,
+				vec![
+					(0, slang::BindingType::ConstantBuffer, 1),
+					(1, slang::BindingType::Texture, 1),
+					(2, slang::BindingType::Sampler, 1),
+				]
+			),
+		]
+	);
+
+	let image = &layout.sets[0].bindings[0];
+	assert_eq!(
+		image.image_format,
+		slang::ImageFormat::SLANGIMAGEFORMATRgba8
+	);
+	assert!(image.stages.contains(slang::Stage::Vertex));
+	assert!(image.stages.contains(slang::Stage::Fragment));
+
+	let shadow_map = &layout.sets[0].bindings[1];
+	assert_eq!(shadow_map.stages, StageFlags::from(slang::Stage::Fragment));
+	assert_eq!(shadow_map.result_type, slang::ScalarType::Float32);
+
+	assert_eq!(layout.sets[1].bindings[0].uniform_size, 16);
+}
+
+#[test]
+fn descriptor_layout_conflict() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let source = r#"
+		[[vk::binding(0)]] Texture2D albedo_map;
+		[[vk::binding(0)]] SamplerState albedo_sampler;
+
+		[shader("fragment")]
+		float4 main(float4 position : SV_Position) : SV_Target {
+			return albedo_map.Sample(albedo_sampler, position.xy);
+		}
+	"#;
+
+	let module = session
+		.load_module_from_source("conflict", "conflict.slang", source)
+		.unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+	let reflection = linked_program.layout(0).unwrap();
+
+	assert_eq!(
+		slang::reflection::DescriptorLayout::from_program(*reflection),
+		Err(slang::reflection::BindingConflict {
+			space: 0,
+			binding: 0,
+			first: (slang::BindingType::Texture, 1),
+			second: (slang::BindingType::Sampler, 1),
+		})
+	);
+}
+
+#[cfg(feature = "ash")]
+#[test]
+fn vulkan_descriptor_layout() {
+	use ash::vk;
+	use slang::reflection::{
+		DescriptorBinding, DescriptorLayout, DescriptorSetLayout, PushConstantRange, StageFlags,
+	};
+
+	let mut graphics = StageFlags::default();
+	graphics.insert(slang::Stage::Vertex);
+	graphics.insert(slang::Stage::Fragment);
+
+	let layout = DescriptorLayout {
+		sets: vec![DescriptorSetLayout {
+			space: 0,
+			bindings: vec![
+				DescriptorBinding {
+					binding: 0,
+					binding_type: slang::BindingType::ConstantBuffer,
+					count: 1,
+					stages: graphics,
+					image_format: slang::ImageFormat::SLANGIMAGEFORMATUnknown,
+					resource_shape: slang::ResourceShape::SlangResourceNone,
+					resource_access: slang::ResourceAccess::None,
+					result_type: slang::ScalarType::None,
+					uniform_size: 64,
+				},
+				DescriptorBinding {
+					binding: 1,
+					binding_type: slang::BindingType::Texture,
+					count: -1,
+					stages: slang::Stage::Fragment.into(),
+					image_format: slang::ImageFormat::SLANGIMAGEFORMATUnknown,
+					resource_shape: slang::ResourceShape::SlangTexture2d,
+					resource_access: slang::ResourceAccess::Read,
+					result_type: slang::ScalarType::Float32,
+					uniform_size: 0,
+				},
+				DescriptorBinding {
+					binding: 2,
+					binding_type: slang::BindingType::MutableRawBuffer,
+					count: 4,
+					stages: slang::Stage::Fragment.into(),
+					image_format: slang::ImageFormat::SLANGIMAGEFORMATUnknown,
+					resource_shape: slang::ResourceShape::SlangResourceNone,
+					resource_access: slang::ResourceAccess::None,
+					result_type: slang::ScalarType::None,
+					uniform_size: 0,
+				},
+			],
+		}],
+		push_constant_ranges: vec![PushConstantRange {
+			offset: 0,
+			size: 16,
+			stages: slang::Stage::Vertex.into(),
+		}],
+	};
+
+	let bindings = layout.sets[0].vk_bindings(1024);
+	let bindings = bindings
+		.iter()
+		.map(|b| {
+			(
+				b.binding,
+				b.descriptor_type,
+				b.descriptor_count,
+				b.stage_flags,
+			)
+		})
+		.collect::<Vec<_>>();
+
+	assert_eq!(
+		bindings,
+		[
+			(
+				0,
+				vk::DescriptorType::UNIFORM_BUFFER,
+				1,
+				vk::ShaderStageFlags::VERTEX | vk::ShaderStageFlags::FRAGMENT
+			),
+			(
+				1,
+				vk::DescriptorType::SAMPLED_IMAGE,
+				1024,
+				vk::ShaderStageFlags::FRAGMENT
+			),
+			(
+				2,
+				vk::DescriptorType::STORAGE_BUFFER,
+				4,
+				vk::ShaderStageFlags::FRAGMENT
+			),
+		]
+	);
+
+	let ranges = layout.vk_push_constant_ranges();
+	assert_eq!(ranges.len(), 1);
+	assert_eq!(ranges[0].stage_flags, vk::ShaderStageFlags::VERTEX);
+	assert_eq!((ranges[0].offset, ranges[0].size), (0, 16));
+
+	assert_eq!(
+		slang::vulkan::descriptor_type(slang::BindingType::PushConstant),
+		None
+	);
+	assert_eq!(
+		slang::vulkan::shader_stage(slang::Stage::Amplification),
+		vk::ShaderStageFlags::TASK_EXT
+	);
+}
+
+#[cfg(feature = "wgpu")]
+#[test]
+fn wgpu_spirv_shader_module() {
+	let words = [0x0723_0203u32, 0x0001_0600];
+	let spirv = slang::Blob::from_vec(words.iter().flat_map(|word| word.to_ne_bytes()).collect());
+	assert!(spirv.spirv_shader_module(Some("spirv")).is_ok());
+
+	let truncated = slang::Blob::from_vec(spirv.as_slice()[..6].to_vec());
+	assert_eq!(
+		truncated.spirv_shader_module(None).err(),
+		Some(slang::webgpu::NotSpirv)
+	);
+
+	let wgsl = slang::Blob::from("@compute @workgroup_size(1) fn main() {}".to_string());
+	assert_eq!(
+		wgsl.spirv_shader_module(None).err(),
+		Some(slang::webgpu::NotSpirv)
+	);
+}
+
+#[cfg(feature = "wgpu")]
+#[test]
+fn wgpu_bind_group_entries() {
+	use std::num::{NonZeroU32, NonZeroU64};
+
+	use slang::reflection::{DescriptorBinding, DescriptorLayout, DescriptorSetLayout};
+
+	let binding = |binding, binding_type, stage: slang::Stage| DescriptorBinding {
+		binding,
+		binding_type,
+		count: 1,
+		stages: stage.into(),
+		image_format: slang::ImageFormat::SLANGIMAGEFORMATUnknown,
+		resource_shape: slang::ResourceShape::SlangResourceNone,
+		resource_access: slang::ResourceAccess::None,
+		result_type: slang::ScalarType::None,
+		uniform_size: 0,
+	};
+
+	let layout = DescriptorLayout {
+		sets: vec![DescriptorSetLayout {
+			space: 1,
+			bindings: vec![
+				DescriptorBinding {
+					uniform_size: 80,
+					..binding(0, slang::BindingType::ConstantBuffer, slang::Stage::Compute)
+				},
+				DescriptorBinding {
+					count: -1,
+					resource_shape: slang::ResourceShape::SlangTexture2dArray,
+					resource_access: slang::ResourceAccess::Read,
+					result_type: slang::ScalarType::Uint32,
+					..binding(1, slang::BindingType::Texture, slang::Stage::Compute)
+				},
+				DescriptorBinding {
+					image_format: slang::ImageFormat::SLANGIMAGEFORMATRgba8,
+					resource_shape: slang::ResourceShape::SlangTexture2d,
+					resource_access: slang::ResourceAccess::Write,
+					..binding(2, slang::BindingType::MutableTeture, slang::Stage::Compute)
+				},
+				DescriptorBinding {
+					resource_shape: slang::ResourceShape::SlangStructuredBuffer,
+					resource_access: slang::ResourceAccess::Read,
+					uniform_size: 16,
+					..binding(3, slang::BindingType::RawBuffer, slang::Stage::Compute)
+				},
+			],
+		}],
+		push_constant_ranges: Vec::new(),
+	};
+
+	let unbounded = NonZeroU32::new(256).unwrap();
+	let groups = layout.wgpu_bind_group_entries(unbounded).unwrap();
+	assert_eq!(groups.len(), 2);
+	assert!(groups[0].is_empty());
+
+	assert_eq!(
+		groups[1],
+		[
+			wgpu::BindGroupLayoutEntry {
+				binding: 0,
+				visibility: wgpu::ShaderStages::COMPUTE,
+				ty: wgpu::BindingType::Buffer {
+					ty: wgpu::BufferBindingType::Uniform,
+					has_dynamic_offset: false,
+					min_binding_size: NonZeroU64::new(80),
+				},
+				count: None,
+			},
+			wgpu::BindGroupLayoutEntry {
+				binding: 1,
+				visibility: wgpu::ShaderStages::COMPUTE,
+				ty: wgpu::BindingType::Texture {
+					sample_type: wgpu::TextureSampleType::Uint,
+					view_dimension: wgpu::TextureViewDimension::D2Array,
+					multisampled: false,
+				},
+				count: Some(unbounded),
+			},
+			wgpu::BindGroupLayoutEntry {
+				binding: 2,
+				visibility: wgpu::ShaderStages::COMPUTE,
+				ty: wgpu::BindingType::StorageTexture {
+					access: wgpu::StorageTextureAccess::WriteOnly,
+					format: wgpu::TextureFormat::Rgba8Unorm,
+					view_dimension: wgpu::TextureViewDimension::D2,
+				},
+				count: None,
+			},
+			wgpu::BindGroupLayoutEntry {
+				binding: 3,
+				visibility: wgpu::ShaderStages::COMPUTE,
+				ty: wgpu::BindingType::Buffer {
+					ty: wgpu::BufferBindingType::Storage { read_only: true },
+					has_dynamic_offset: false,
+					min_binding_size: NonZeroU64::new(16),
+				},
+				count: None,
+			},
+		]
+	);
+
+	let far_space = DescriptorLayout {
+		sets: vec![DescriptorSetLayout {
+			space: 1000,
+			bindings: vec![binding(
+				0,
+				slang::BindingType::Sampler,
+				slang::Stage::Fragment,
+			)],
+		}],
+		push_constant_ranges: Vec::new(),
+	};
+
+	assert_eq!(
+		far_space.wgpu_bind_group_entries(unbounded),
+		Err(slang::webgpu::BindGroupLayoutError::Space { space: 1000 })
+	);
+
+	let unknown_format = DescriptorSetLayout {
+		space: 0,
+		bindings: vec![DescriptorBinding {
+			resource_shape: slang::ResourceShape::SlangTexture2d,
+			..binding(4, slang::BindingType::MutableTeture, slang::Stage::Fragment)
+		}],
+	};
+
+	assert_eq!(
+		unknown_format.wgpu_entries(unbounded),
+		Err(slang::webgpu::BindGroupLayoutError::ImageFormat {
+			space: 0,
+			binding: 4,
+			image_format: slang::ImageFormat::SLANGIMAGEFORMATUnknown,
+		})
+	);
+
+	let wgsl = slang::Blob::from_vec(b"@compute @workgroup_size(1) fn main() {}".to_vec());
+	let module = wgsl.wgsl_shader_module(Some("main")).unwrap();
+	assert_eq!(module.label, Some("main"));
+	assert!(
+		matches!(module.source, wgpu::ShaderSource::Wgsl(source) if source.starts_with("@compute"))
+	);
+}
+
+#[test]
+fn varying_attributes() {
+	use slang::reflection::VaryingFormat;
+
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = create_session(&global_session);
+
+	let module = session.load_module("varyings.slang").unwrap();
+	let vertex = module.find_entry_point_by_name("vertex_main").unwrap();
+	let fragment = module.find_entry_point_by_name("fragment_main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			vertex.downcast().clone(),
+			fragment.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+	let reflection = linked_program.layout(0).unwrap();
+
+	let float = |components| VaryingFormat {
+		scalar_type: slang::ScalarType::Float32,
+		components,
+	};
+
+	let vertex_inputs = reflection.entry_point_by_index(0).unwrap().vertex_inputs();
+	let vertex_inputs = vertex_inputs
+		.iter()
+		.map(|input| (input.name.unwrap(), input.location, input.format))
+		.collect::<Vec<_>>();
+
+	// `SV_VertexID` isn't a vertex attribute.
+	assert_eq!(
+		vertex_inputs,
+		[
+			("position", 0, float(3)),
+			("normal", 1, float(3)),
+			("uv", 2, float(2)),
+			(
+				"joints",
+				3,
+				VaryingFormat {
+					scalar_type: slang::ScalarType::Uint32,
+					components: 4,
+				}
+			),
+		]
+	);
+
+	let fragment_outputs = reflection
+		.entry_point_by_index(1)
+		.unwrap()
+		.fragment_outputs();
+	let fragment_outputs = fragment_outputs
+		.iter()
+		.map(|output| (output.semantic_index, output.location, output.format))
+		.collect::<Vec<_>>();
+
+	assert_eq!(fragment_outputs, [(0, 0, float(4)), (1, 1, float(4))]);
+}
+
+#[test]
+fn host_callable() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let search_path = std::ffi::CString::new("shaders").unwrap();
+
+	let targets = [slang::TargetDesc::default().format(slang::CompileTarget::ShaderHostCallable)];
+	let search_paths = [search_path.as_ptr()];
+
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.search_paths(&search_paths);
+
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let module = session.load_module("host.slang").unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+
+	let library = linked_program.entry_point_host_callable(0, 0).unwrap();
+	assert!(library.find_func_by_name("main").is_some());
+	assert!(library.find_func_by_name("missing").is_none());
+	assert!(library.find_func_by_name("ma\0in").is_none());
+
+	// Structured buffers are passed as a pointer and an element count on the CPU.
+	#[repr(C)]
+	struct Globals {
+		output: *mut f32,
+		output_count: usize,
+	}
+
+	#[repr(C)]
+	struct Params {
+		scale: f32,
+	}
+
+	let mut output = [0.0_f32; 8];
+
+	let mut globals = Globals {
+		output: output.as_mut_ptr(),
+		output_count: output.len(),
+	};
+
+	let kernel = library.find_compute_kernel("main").unwrap();
+	unsafe { kernel.dispatch([2, 1, 1], &mut Params { scale: 2.0 }, &mut globals) };
+
+	assert_eq!(output, [0.0, 2.0, 4.0, 6.0, 8.0, 10.0, 12.0, 14.0]);
+}
+
+#[test]
+fn result_file_system() {
+	let global_session = slang::GlobalSession::new().unwrap();
+
+	let search_path = std::ffi::CString::new("shaders").unwrap();
+
+	let targets = [slang::TargetDesc::default().format(slang::CompileTarget::CppSource)];
+	let search_paths = [search_path.as_ptr()];
+
+	let session_desc = slang::SessionDesc::default()
+		.targets(&targets)
+		.search_paths(&search_paths);
+
+	let session = global_session.create_session(&session_desc).unwrap();
+
+	let module = session.load_module("host.slang").unwrap();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+
+	let program = session
+		.create_composite_component_type(&[
+			module.downcast().clone(),
+			entry_point.downcast().clone(),
+		])
+		.unwrap();
+	let linked_program = program.link().unwrap();
+
+	let code = linked_program.entry_point_code(0, 0).unwrap();
+	let file_system = linked_program.result_file_system(0, 0).unwrap();
+
+	let files = file_system.files().unwrap();
+	assert!(!files.is_empty());
+
+	for file in &files {
+		assert_eq!(file_system.path_type(file).unwrap(), slang::PathType::File);
+	}
+
+	// One of the files is the code itself.
+	assert!(files
+		.iter()
+		.any(|file| file_system.load_file(file).unwrap().as_slice() == code.as_slice()));
+	assert!(file_system.load_file("missing.cpp").is_err());
+	assert!(matches!(
+		file_system.path_type("bad\0path"),
+		Err(slang::Error::Code(result)) if result == slang::E_INVALID_ARG
+	));
+}
@@ -0,0 +1,96 @@
+//! Conversions from Slang reflection to `ash` Vulkan types.
+
+use ash::vk;
+
+use crate::reflection::{DescriptorLayout, DescriptorSetLayout, PushConstantRange, StageFlags};
+use crate::{BindingType, Stage};
+
+pub fn shader_stage(stage: Stage) -> vk::ShaderStageFlags {
+	match stage {
+		Stage::Vertex => vk::ShaderStageFlags::VERTEX,
+		Stage::Hull => vk::ShaderStageFlags::TESSELLATION_CONTROL,
+		Stage::Domain => vk::ShaderStageFlags::TESSELLATION_EVALUATION,
+		Stage::Geometry => vk::ShaderStageFlags::GEOMETRY,
+		Stage::Fragment => vk::ShaderStageFlags::FRAGMENT,
+		Stage::Compute => vk::ShaderStageFlags::COMPUTE,
+		Stage::RayGeneration => vk::ShaderStageFlags::RAYGEN_KHR,
+		Stage::Intersection => vk::ShaderStageFlags::INTERSECTION_KHR,
+		Stage::AnyHit => vk::ShaderStageFlags::ANY_HIT_KHR,
+		Stage::ClosestHit => vk::ShaderStageFlags::CLOSEST_HIT_KHR,
+		Stage::Miss => vk::ShaderStageFlags::MISS_KHR,
+		Stage::Callable => vk::ShaderStageFlags::CALLABLE_KHR,
+		Stage::Mesh => vk::ShaderStageFlags::MESH_EXT,
+		Stage::Amplification => vk::ShaderStageFlags::TASK_EXT,
+		_ => vk::ShaderStageFlags::empty(),
+	}
+}
+
+impl From<StageFlags> for vk::ShaderStageFlags {
+	fn from(stages: StageFlags) -> Self {
+		stages
+			.iter()
+			.fold(Self::empty(), |flags, stage| flags | shader_stage(stage))
+	}
+}
+
+/// Returns `None` for binding types that aren't descriptors, like push constants.
+pub fn descriptor_type(binding_type: BindingType) -> Option<vk::DescriptorType> {
+	Some(match binding_type {
+		BindingType::Sampler => vk::DescriptorType::SAMPLER,
+		BindingType::Texture => vk::DescriptorType::SAMPLED_IMAGE,
+		BindingType::MutableTeture => vk::DescriptorType::STORAGE_IMAGE,
+		BindingType::CombinedTextureSampler => vk::DescriptorType::COMBINED_IMAGE_SAMPLER,
+		BindingType::ConstantBuffer => vk::DescriptorType::UNIFORM_BUFFER,
+		BindingType::TypedBuffer => vk::DescriptorType::UNIFORM_TEXEL_BUFFER,
+		BindingType::MutableTypedBuffer => vk::DescriptorType::STORAGE_TEXEL_BUFFER,
+		BindingType::RawBuffer | BindingType::MutableRawBuffer => {
+			vk::DescriptorType::STORAGE_BUFFER
+		}
+		BindingType::InputRenderTarget => vk::DescriptorType::INPUT_ATTACHMENT,
+		BindingType::InlineUniformData => vk::DescriptorType::INLINE_UNIFORM_BLOCK,
+		BindingType::RayTracingAccelerationStructure => {
+			vk::DescriptorType::ACCELERATION_STRUCTURE_KHR
+		}
+		_ => return None,
+	})
+}
+
+impl DescriptorSetLayout {
+	/// Unsized arrays get `unbounded_count` descriptors, to be used with
+	/// `VARIABLE_DESCRIPTOR_COUNT`. Bindings that aren't descriptors are left out.
+	pub fn vk_bindings(
+		&self,
+		unbounded_count: u32,
+	) -> Vec<vk::DescriptorSetLayoutBinding<'static>> {
+		self.bindings
+			.iter()
+			.filter_map(|binding| {
+				let descriptor_count = u32::try_from(binding.count).unwrap_or(unbounded_count);
+
+				Some(
+					vk::DescriptorSetLayoutBinding::default()
+						.binding(binding.binding as u32)
+						.descriptor_type(descriptor_type(binding.binding_type)?)
+						.descriptor_count(descriptor_count)
+						.stage_flags(binding.stages.into()),
+				)
+			})
+			.collect()
+	}
+}
+
+impl From<&PushConstantRange> for vk::PushConstantRange {
+	fn from(range: &PushConstantRange) -> Self {
+		vk::PushConstantRange {
+			stage_flags: range.stages.into(),
+			offset: range.offset as u32,
+			size: range.size as u32,
+		}
+	}
+}
+
+impl DescriptorLayout {
+	pub fn vk_push_constant_ranges(&self) -> Vec<vk::PushConstantRange> {
+		self.push_constant_ranges.iter().map(Into::into).collect()
+	}
+}
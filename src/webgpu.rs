@@ -0,0 +1,353 @@
+//! Conversions from Slang reflection to `wgpu` types.
+
+use std::fmt;
+use std::num::{NonZeroU32, NonZeroU64};
+
+use crate::reflection::{DescriptorBinding, DescriptorLayout, DescriptorSetLayout, StageFlags};
+use crate::{BindingType, Blob, ImageFormat, ResourceAccess, ResourceShape, ScalarType, Stage};
+
+/// No wgpu backend supports more bind groups than this.
+const MAX_BIND_GROUPS: i64 = 8;
+
+/// A binding that can't be expressed in a wgpu bind group layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BindGroupLayoutError {
+	/// wgpu has no equivalent, e.g. for combined texture samplers or texel buffers.
+	BindingType {
+		space: i64,
+		binding: i64,
+		binding_type: BindingType,
+	},
+	/// wgpu has no equivalent, e.g. for subpass inputs.
+	ResourceShape {
+		space: i64,
+		binding: i64,
+		resource_shape: ResourceShape,
+	},
+	/// Storage textures need a format wgpu supports, declared with `[format("...")]`.
+	ImageFormat {
+		space: i64,
+		binding: i64,
+		image_format: ImageFormat,
+	},
+	/// Spaces are bind group indices, which are limited to a few.
+	Space { space: i64 },
+}
+
+impl fmt::Display for BindGroupLayoutError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::BindingType {
+				space,
+				binding,
+				binding_type,
+			} => write!(
+				f,
+				"binding {binding} in space {space}: {binding_type:?} isn't supported by wgpu"
+			),
+			Self::ResourceShape {
+				space,
+				binding,
+				resource_shape,
+			} => write!(
+				f,
+				"binding {binding} in space {space}: {resource_shape:?} isn't supported by wgpu"
+			),
+			Self::ImageFormat {
+				space,
+				binding,
+				image_format,
+			} => write!(
+				f,
+				"binding {binding} in space {space}: storage textures need a format supported by wgpu, got {image_format:?}"
+			),
+			Self::Space { space } => write!(
+				f,
+				"space {space} isn't a bind group index, wgpu supports at most {MAX_BIND_GROUPS}"
+			),
+		}
+	}
+}
+
+impl std::error::Error for BindGroupLayoutError {}
+
+/// A blob passed to [`Blob::spirv_shader_module`] that doesn't start with the SPIR-V magic number
+/// or isn't made of 32 bit words.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotSpirv;
+
+impl fmt::Display for NotSpirv {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "the blob doesn't hold SPIR-V")
+	}
+}
+
+impl std::error::Error for NotSpirv {}
+
+pub fn shader_stage(stage: Stage) -> wgpu::ShaderStages {
+	match stage {
+		Stage::Vertex => wgpu::ShaderStages::VERTEX,
+		Stage::Fragment => wgpu::ShaderStages::FRAGMENT,
+		Stage::Compute => wgpu::ShaderStages::COMPUTE,
+		Stage::Mesh => wgpu::ShaderStages::MESH,
+		Stage::Amplification => wgpu::ShaderStages::TASK,
+		_ => wgpu::ShaderStages::NONE,
+	}
+}
+
+impl From<StageFlags> for wgpu::ShaderStages {
+	fn from(stages: StageFlags) -> Self {
+		stages
+			.iter()
+			.fold(Self::NONE, |flags, stage| flags | shader_stage(stage))
+	}
+}
+
+/// Returns `None` for formats wgpu doesn't have.
+pub fn texture_format(image_format: ImageFormat) -> Option<wgpu::TextureFormat> {
+	use wgpu::TextureFormat as F;
+	use ImageFormat as I;
+
+	Some(match image_format {
+		I::SLANGIMAGEFORMATRgba32f => F::Rgba32Float,
+		I::SLANGIMAGEFORMATRgba16f => F::Rgba16Float,
+		I::SLANGIMAGEFORMATRg32f => F::Rg32Float,
+		I::SLANGIMAGEFORMATRg16f => F::Rg16Float,
+		I::SLANGIMAGEFORMATR11fG11fB10f => F::Rg11b10Ufloat,
+		I::SLANGIMAGEFORMATR32f => F::R32Float,
+		I::SLANGIMAGEFORMATR16f => F::R16Float,
+		I::SLANGIMAGEFORMATRgba16 => F::Rgba16Unorm,
+		I::SLANGIMAGEFORMATRgb10A2 => F::Rgb10a2Unorm,
+		I::SLANGIMAGEFORMATRgba8 => F::Rgba8Unorm,
+		I::SLANGIMAGEFORMATRg16 => F::Rg16Unorm,
+		I::SLANGIMAGEFORMATRg8 => F::Rg8Unorm,
+		I::SLANGIMAGEFORMATR16 => F::R16Unorm,
+		I::SLANGIMAGEFORMATR8 => F::R8Unorm,
+		I::SLANGIMAGEFORMATRgba16Snorm => F::Rgba16Snorm,
+		I::SLANGIMAGEFORMATRgba8Snorm => F::Rgba8Snorm,
+		I::SLANGIMAGEFORMATRg16Snorm => F::Rg16Snorm,
+		I::SLANGIMAGEFORMATRg8Snorm => F::Rg8Snorm,
+		I::SLANGIMAGEFORMATR16Snorm => F::R16Snorm,
+		I::SLANGIMAGEFORMATR8Snorm => F::R8Snorm,
+		I::SLANGIMAGEFORMATRgba32i => F::Rgba32Sint,
+		I::SLANGIMAGEFORMATRgba16i => F::Rgba16Sint,
+		I::SLANGIMAGEFORMATRgba8i => F::Rgba8Sint,
+		I::SLANGIMAGEFORMATRg32i => F::Rg32Sint,
+		I::SLANGIMAGEFORMATRg16i => F::Rg16Sint,
+		I::SLANGIMAGEFORMATRg8i => F::Rg8Sint,
+		I::SLANGIMAGEFORMATR32i => F::R32Sint,
+		I::SLANGIMAGEFORMATR16i => F::R16Sint,
+		I::SLANGIMAGEFORMATR8i => F::R8Sint,
+		I::SLANGIMAGEFORMATRgba32ui => F::Rgba32Uint,
+		I::SLANGIMAGEFORMATRgba16ui => F::Rgba16Uint,
+		I::SLANGIMAGEFORMATRgb10A2ui => F::Rgb10a2Uint,
+		I::SLANGIMAGEFORMATRgba8ui => F::Rgba8Uint,
+		I::SLANGIMAGEFORMATRg32ui => F::Rg32Uint,
+		I::SLANGIMAGEFORMATRg16ui => F::Rg16Uint,
+		I::SLANGIMAGEFORMATRg8ui => F::Rg8Uint,
+		I::SLANGIMAGEFORMATR32ui => F::R32Uint,
+		I::SLANGIMAGEFORMATR16ui => F::R16Uint,
+		I::SLANGIMAGEFORMATR8ui => F::R8Uint,
+		I::SLANGIMAGEFORMATR64ui => F::R64Uint,
+		I::SLANGIMAGEFORMATBgra8 => F::Bgra8Unorm,
+		_ => return None,
+	})
+}
+
+/// Returns `None` for shapes that aren't textures, or that wgpu doesn't have.
+pub fn texture_view_dimension(shape: ResourceShape) -> Option<wgpu::TextureViewDimension> {
+	use ResourceShape as S;
+
+	Some(match shape {
+		S::SlangTexture1d => wgpu::TextureViewDimension::D1,
+		S::SlangTexture2d | S::SlangTexture2dMultisample => wgpu::TextureViewDimension::D2,
+		S::SlangTexture2dArray | S::SlangTexture2dMultisampleArray => {
+			wgpu::TextureViewDimension::D2Array
+		}
+		S::SlangTexture3d => wgpu::TextureViewDimension::D3,
+		S::SlangTextureCube => wgpu::TextureViewDimension::Cube,
+		S::SlangTextureCubeArray => wgpu::TextureViewDimension::CubeArray,
+		_ => return None,
+	})
+}
+
+impl DescriptorBinding {
+	/// Samplers are always filtering and float textures filterable unless multisampled, since
+	/// Slang doesn't tell how they're used.
+	pub fn wgpu_binding_type(&self, space: i64) -> Result<wgpu::BindingType, BindGroupLayoutError> {
+		let unsupported_shape = || BindGroupLayoutError::ResourceShape {
+			space,
+			binding: self.binding,
+			resource_shape: self.resource_shape,
+		};
+
+		Ok(match self.binding_type {
+			BindingType::Sampler => wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+			BindingType::Texture => {
+				let multisampled = matches!(
+					self.resource_shape,
+					ResourceShape::SlangTexture2dMultisample
+						| ResourceShape::SlangTexture2dMultisampleArray
+				);
+
+				let sample_type = match self.result_type {
+					ScalarType::Int8
+					| ScalarType::Int16
+					| ScalarType::Int32
+					| ScalarType::Int64 => wgpu::TextureSampleType::Sint,
+					ScalarType::Uint8
+					| ScalarType::Uint16
+					| ScalarType::Uint32
+					| ScalarType::Uint64 => wgpu::TextureSampleType::Uint,
+					_ => wgpu::TextureSampleType::Float {
+						filterable: !multisampled,
+					},
+				};
+
+				wgpu::BindingType::Texture {
+					sample_type,
+					view_dimension: texture_view_dimension(self.resource_shape)
+						.ok_or_else(unsupported_shape)?,
+					multisampled,
+				}
+			}
+			BindingType::MutableTeture => {
+				let access = match self.resource_access {
+					ResourceAccess::Read => wgpu::StorageTextureAccess::ReadOnly,
+					ResourceAccess::Write => wgpu::StorageTextureAccess::WriteOnly,
+					_ => wgpu::StorageTextureAccess::ReadWrite,
+				};
+
+				let format =
+					texture_format(self.image_format).ok_or(BindGroupLayoutError::ImageFormat {
+						space,
+						binding: self.binding,
+						image_format: self.image_format,
+					})?;
+
+				wgpu::BindingType::StorageTexture {
+					access,
+					format,
+					view_dimension: texture_view_dimension(self.resource_shape)
+						.ok_or_else(unsupported_shape)?,
+				}
+			}
+			BindingType::ConstantBuffer => self.buffer(wgpu::BufferBindingType::Uniform),
+			BindingType::RawBuffer => {
+				self.buffer(wgpu::BufferBindingType::Storage { read_only: true })
+			}
+			BindingType::MutableRawBuffer => {
+				self.buffer(wgpu::BufferBindingType::Storage { read_only: false })
+			}
+			BindingType::RayTracingAccelerationStructure => {
+				wgpu::BindingType::AccelerationStructure {
+					vertex_return: false,
+				}
+			}
+			binding_type => {
+				return Err(BindGroupLayoutError::BindingType {
+					space,
+					binding: self.binding,
+					binding_type,
+				})
+			}
+		})
+	}
+
+	fn buffer(&self, ty: wgpu::BufferBindingType) -> wgpu::BindingType {
+		wgpu::BindingType::Buffer {
+			ty,
+			has_dynamic_offset: false,
+			min_binding_size: NonZeroU64::new(self.uniform_size as u64),
+		}
+	}
+}
+
+impl DescriptorSetLayout {
+	/// Unsized arrays get `unbounded_count` elements, which needs
+	/// `wgpu::Features::PARTIALLY_BOUND_BINDING_ARRAY` to leave some of them unbound.
+	pub fn wgpu_entries(
+		&self,
+		unbounded_count: NonZeroU32,
+	) -> Result<Vec<wgpu::BindGroupLayoutEntry>, BindGroupLayoutError> {
+		self.bindings
+			.iter()
+			.map(|binding| {
+				let count = match binding.count {
+					1 => None,
+					count => Some(u32::try_from(count).map_or(unbounded_count, |count| {
+						NonZeroU32::new(count).unwrap_or(unbounded_count)
+					})),
+				};
+
+				Ok(wgpu::BindGroupLayoutEntry {
+					binding: binding.binding as u32,
+					visibility: binding.stages.into(),
+					ty: binding.wgpu_binding_type(self.space)?,
+					count,
+				})
+			})
+			.collect()
+	}
+}
+
+impl DescriptorLayout {
+	/// The entries of each bind group, indexed by space. Spaces without bindings get an empty
+	/// bind group.
+	pub fn wgpu_bind_group_entries(
+		&self,
+		unbounded_count: NonZeroU32,
+	) -> Result<Vec<Vec<wgpu::BindGroupLayoutEntry>>, BindGroupLayoutError> {
+		if let Some(set) = self
+			.sets
+			.iter()
+			.find(|set| !(0..MAX_BIND_GROUPS).contains(&set.space))
+		{
+			return Err(BindGroupLayoutError::Space { space: set.space });
+		}
+
+		let group_count = self.sets.last().map_or(0, |set| set.space as usize + 1);
+		let mut groups = vec![Vec::new(); group_count];
+
+		for set in &self.sets {
+			groups[set.space as usize] = set.wgpu_entries(unbounded_count)?;
+		}
+
+		Ok(groups)
+	}
+}
+
+impl Blob {
+	pub fn spirv_shader_module<'a>(
+		&'a self,
+		label: Option<&'a str>,
+	) -> Result<wgpu::ShaderModuleDescriptor<'a>, NotSpirv> {
+		const MAGIC_NUMBER: u32 = 0x0723_0203;
+
+		// `make_spirv_raw` panics on anything else, it accepts either byte order.
+		let bytes = self.as_slice();
+		let magic_number = bytes
+			.first_chunk()
+			.map(|&prefix| u32::from_ne_bytes(prefix))
+			.filter(|&prefix| prefix == MAGIC_NUMBER || prefix == MAGIC_NUMBER.swap_bytes());
+
+		if magic_number.is_none() || !bytes.len().is_multiple_of(4) {
+			return Err(NotSpirv);
+		}
+
+		Ok(wgpu::ShaderModuleDescriptor {
+			label,
+			source: wgpu::ShaderSource::SpirV(wgpu::util::make_spirv_raw(bytes)),
+		})
+	}
+
+	pub fn wgsl_shader_module<'a>(
+		&'a self,
+		label: Option<&'a str>,
+	) -> Result<wgpu::ShaderModuleDescriptor<'a>, std::str::Utf8Error> {
+		Ok(wgpu::ShaderModuleDescriptor {
+			label,
+			source: wgpu::ShaderSource::Wgsl(self.as_str()?.into()),
+		})
+	}
+}
@@ -0,0 +1,12 @@
+fn main() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = global_session
+		.create_session(&slang::SessionDesc::default())
+		.unwrap();
+
+	let module = session.load_module("test.slang").unwrap().into_value();
+	let decl = module.module_reflection();
+
+	drop(module);
+	println!("{}", decl.child_count());
+}
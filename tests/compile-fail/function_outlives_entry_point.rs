@@ -0,0 +1,13 @@
+fn main() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = global_session
+		.create_session(&slang::SessionDesc::default())
+		.unwrap();
+
+	let module = session.load_module("test.slang").unwrap().into_value();
+	let entry_point = module.find_entry_point_by_name("main").unwrap();
+	let function = entry_point.function_reflection();
+
+	drop(entry_point);
+	println!("{}", function.name());
+}
@@ -0,0 +1,15 @@
+use slang::Downcast;
+
+fn main() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = global_session
+		.create_session(&slang::SessionDesc::default())
+		.unwrap();
+
+	let module = session.load_module("test.slang").unwrap().into_value();
+	let program = module.downcast().clone();
+	let shader = program.layout(0).unwrap().into_value();
+
+	drop(program);
+	println!("{}", shader.parameter_count());
+}
@@ -0,0 +1,20 @@
+use slang::Downcast;
+
+fn main() {
+	let global_session = slang::GlobalSession::new().unwrap();
+	let session = global_session
+		.create_session(&slang::SessionDesc::default())
+		.unwrap();
+
+	let module = session.load_module("test.slang").unwrap().into_value();
+	let program = module.downcast().clone();
+
+	// Types are only reachable through the shader, but still borrow the program.
+	let ty = {
+		let shader = program.layout(0).unwrap().into_value();
+		shader.find_type_by_name("float").unwrap()
+	};
+
+	drop(program);
+	println!("{:?}", ty.name());
+}